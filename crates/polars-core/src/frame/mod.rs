@@ -18,12 +18,14 @@ pub mod explode;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
+mod policy;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
 mod upstream_traits;
 
 pub use chunks::*;
+pub use policy::DataFramePolicy;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -136,6 +138,8 @@ pub struct DataFrame {
     pub(crate) columns: Vec<Series>,
     /// The hanlde to the DataFrame on the arena.
     pub(crate) uuid: Uuid,
+    /// The policy registered with the monitor when this DataFrame is scanned.
+    pub(crate) policy: Option<DataFramePolicy>,
 }
 
 impl DataFrame {
@@ -184,6 +188,23 @@ impl DataFrame {
         self.uuid
     }
 
+    /// Attach a privacy policy to this [`DataFrame`].
+    ///
+    /// The policy is registered with the monitor when a query with policy checking enabled scans
+    /// this [`DataFrame`]. Frames derived from it through eager operations do not keep the policy.
+    pub fn with_policy(mut self, policy: DataFramePolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    pub fn set_policy(&mut self, policy: Option<DataFramePolicy>) {
+        self.policy = policy;
+    }
+
+    pub fn get_policy(&self) -> Option<&DataFramePolicy> {
+        self.policy.as_ref()
+    }
+
     // Reduce monomorphization.
     fn try_apply_columns(
         &self,
@@ -310,6 +331,7 @@ impl DataFrame {
         Ok(DataFrame {
             columns: series_cols,
             uuid: Uuid::nil(),
+            policy: None,
         })
     }
 
@@ -420,6 +442,7 @@ impl DataFrame {
         DataFrame {
             columns,
             uuid: Uuid::nil(),
+            policy: None,
         }
     }
 
@@ -446,6 +469,7 @@ impl DataFrame {
         Ok(DataFrame {
             columns,
             uuid: Uuid::nil(),
+            policy: None,
         })
     }
 
@@ -0,0 +1,33 @@
+#[cfg(feature = "picachv")]
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[cfg(feature = "picachv")]
+use crate::prelude::*;
+
+/// A privacy policy attached to an in-memory [`DataFrame`](super::DataFrame).
+///
+/// The policy is registered with the monitor when a query with policy checking enabled scans
+/// the frame.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataFramePolicy {
+    /// A file holding the serialized policy.
+    File(Arc<PathBuf>),
+    /// A serialized policy.
+    Bin(Arc<[u8]>),
+}
+
+#[cfg(feature = "picachv")]
+impl DataFramePolicy {
+    /// Register the policy with the monitor context `ctx_id`. Returns the uuid of the registered
+    /// dataframe.
+    pub fn register(&self, ctx_id: uuid::Uuid) -> PolarsResult<uuid::Uuid> {
+        let policy = match self {
+            DataFramePolicy::File(path) => Cow::Owned(std::fs::read(path.as_ref())?),
+            DataFramePolicy::Bin(policy) => Cow::Borrowed(policy.as_ref()),
+        };
+
+        picachv::native::register_policy_dataframe_bin(ctx_id, &policy).map_err(PolarsError::from)
+    }
+}
@@ -47,6 +47,41 @@ impl Display for ErrString {
     }
 }
 
+/// A query that the policy monitor refused to let through.
+#[derive(Debug, Clone)]
+pub struct PolicyViolationError {
+    /// The reason given by the monitor, i.e. the policy clause that was not satisfied.
+    pub clause: String,
+    /// The operator of the query at which the monitor refused it, if known.
+    pub operator: Option<String>,
+    /// The column the refused operator worked on, if known.
+    pub column: Option<String>,
+}
+
+impl PolicyViolationError {
+    pub fn new(clause: impl Into<String>) -> Self {
+        PolicyViolationError {
+            clause: clause.into(),
+            operator: None,
+            column: None,
+        }
+    }
+}
+
+impl Display for PolicyViolationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.clause)?;
+        if let Some(operator) = &self.operator {
+            write!(f, " (in `{operator}`")?;
+            if let Some(column) = &self.column {
+                write!(f, " on column `{column}`")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum PolarsError {
     #[error("not found: {0}")]
@@ -69,6 +104,8 @@ pub enum PolarsError {
     NoData(ErrString),
     #[error("{0}")]
     OutOfBounds(ErrString),
+    #[error("policy violation: {0}")]
+    PolicyViolation(Box<PolicyViolationError>),
     #[error("field not found: {0}")]
     SchemaFieldNotFound(ErrString),
     #[error("{0}")]
@@ -113,6 +150,22 @@ impl From<object_store::Error> for PolarsError {
     }
 }
 
+#[cfg(feature = "picachv")]
+impl From<picachv::PicachvError> for PolarsError {
+    fn from(err: picachv::PicachvError) -> Self {
+        use picachv::PicachvError;
+        match err {
+            PicachvError::PrivacyError(msg) => {
+                PolarsError::PolicyViolation(Box::new(PolicyViolationError::new(msg.to_string())))
+            },
+            PicachvError::InvalidOperation(msg) => {
+                polars_err!(InvalidOperation: "policy monitor: {}", msg)
+            },
+            err => polars_err!(ComputeError: "policy monitor: {}", err),
+        }
+    }
+}
+
 #[cfg(feature = "avro-schema")]
 impl From<avro_schema::error::Error> for PolarsError {
     fn from(value: avro_schema::error::Error) -> Self {
@@ -196,6 +249,10 @@ impl PolarsError {
             },
             NoData(msg) => NoData(func(msg).into()),
             OutOfBounds(msg) => OutOfBounds(func(msg).into()),
+            PolicyViolation(error) => PolicyViolation(Box::new(PolicyViolationError {
+                clause: func(&error.clause),
+                ..(**error).clone()
+            })),
             SchemaFieldNotFound(msg) => SchemaFieldNotFound(func(msg).into()),
             SchemaMismatch(msg) => SchemaMismatch(func(msg).into()),
             ShapeMismatch(msg) => ShapeMismatch(func(msg).into()),
@@ -219,6 +276,21 @@ impl PolarsError {
             error: Box::new(self),
         }
     }
+
+    /// Attribute a policy violation to the `operator` and `column` at which the monitor refused
+    /// the query. A location that is already known is kept, other errors are returned as is.
+    pub fn with_policy_context(self, operator: &str, column: Option<&str>) -> Self {
+        match self {
+            PolarsError::PolicyViolation(mut error) => {
+                if error.operator.is_none() {
+                    error.operator = Some(operator.to_string());
+                    error.column = column.map(|column| column.to_string());
+                }
+                PolarsError::PolicyViolation(error)
+            },
+            err => err,
+        }
+    }
 }
 
 pub fn map_err<E: Error>(error: E) -> PolarsError {
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
+use bytes::Bytes;
 use object_store::local::LocalFileSystem;
+use object_store::path::Path;
 use object_store::ObjectStore;
 use once_cell::sync::Lazy;
 use polars_error::{polars_bail, to_compute_err, PolarsError, PolarsResult};
@@ -8,7 +10,7 @@ use polars_utils::aliases::PlHashMap;
 use tokio::sync::RwLock;
 use url::Url;
 
-use super::{parse_url, CloudLocation, CloudOptions, CloudType};
+use super::{parse_url, CloudLocation, CloudOptions, CloudType, PolarsObjectStore};
 
 /// Object stores must be cached. Every object-store will do DNS lookups and
 /// get rate limited when querying the DNS (can take up to 5s).
@@ -127,3 +129,10 @@ pub async fn build_object_store(
     }
     Ok((cloud_location, store))
 }
+
+/// Fetch the entire object at `url`.
+pub async fn get_object(url: &str, options: Option<&CloudOptions>) -> PolarsResult<Bytes> {
+    let (CloudLocation { prefix, .. }, store) = build_object_store(url, options).await?;
+    let path = Path::from_url_path(prefix).map_err(to_compute_err)?;
+    PolarsObjectStore::new(store).get(&path).await
+}
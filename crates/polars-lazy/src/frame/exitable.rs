@@ -8,35 +8,39 @@ use super::*;
 
 impl LazyFrame {
     pub fn collect_concurrently(self) -> PolarsResult<InProcessQuery> {
-        if let Err(e) = init_monitor() {
-            match e {
-                PicachvError::Already(_) => {},
-                _ => return Err(PolarsError::ComputeError(e.to_string().into())),
-            }
-        }
-
-        // Initialize a new context id.
-        let ctx_id = open_new().map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false, ctx_id, false)?;
+        let ctx_id = self.ctx_id;
+        let policy_check = self.policy_checking_enabled;
+        let (mut state, mut physical_plan, _) =
+            self.prepare_collect(false, ctx_id, policy_check)?;
+        state.set_ctx_id(ctx_id);
+        state.set_policy_checking(policy_check);
 
         let (tx, rx) = channel();
         let token = state.cancel_token();
         POOL.spawn_fifo(move || {
-            let result = physical_plan.execute(&mut state);
+            let result = physical_plan
+                .execute(&mut state)
+                .map(|df| (df, state.get_active_df_uuid()));
             tx.send(result).unwrap();
         });
 
         Ok(InProcessQuery {
             rx: Arc::new(Mutex::new(rx)),
             token,
+            policy_check: policy_check.then_some(ctx_id),
         })
     }
 }
 
+/// The result of a query, with the uuid the monitor tracks it under.
+type QueryResult = PolarsResult<(DataFrame, Uuid)>;
+
 #[derive(Clone)]
 pub struct InProcessQuery {
-    rx: Arc<Mutex<Receiver<PolarsResult<DataFrame>>>>,
+    rx: Arc<Mutex<Receiver<QueryResult>>>,
     token: Arc<AtomicBool>,
+    /// The monitor context of a policy-checked query.
+    policy_check: Option<Uuid>,
 }
 
 impl InProcessQuery {
@@ -45,19 +49,28 @@ impl InProcessQuery {
         self.token.store(true, Ordering::Relaxed)
     }
 
+    /// Run the final check of the monitor if the query is policy-checked.
+    fn finish(&self, result: QueryResult) -> PolarsResult<DataFrame> {
+        let (df, df_uuid) = result?;
+        if let Some(ctx_id) = self.policy_check {
+            finalize_query(ctx_id, df_uuid)?;
+        }
+        Ok(df)
+    }
+
     /// Fetch the result.
 
     /// If it is ready, a materialized DataFrame is returned.
     /// If it is not ready it will return `None`.
     pub fn fetch(&self) -> Option<PolarsResult<DataFrame>> {
         let rx = self.rx.lock().unwrap();
-        rx.try_recv().ok()
+        rx.try_recv().ok().map(|result| self.finish(result))
     }
 
     /// Await the result synchronously.
     pub fn fetch_blocking(&self) -> PolarsResult<DataFrame> {
         let rx = self.rx.lock().unwrap();
-        rx.recv().unwrap()
+        self.finish(rx.recv().unwrap())
     }
 }
 
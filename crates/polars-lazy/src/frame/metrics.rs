@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use polars_core::prelude::*;
+use uuid::Uuid;
+
+/// The metrics of a query collected with [`LazyFrame::collect_with_metrics`].
+///
+/// [`LazyFrame::collect_with_metrics`]: crate::frame::LazyFrame::collect_with_metrics
+#[derive(Clone, Debug)]
+pub struct QueryMetrics {
+    /// The time of the whole query, including the optimization and the final policy check.
+    pub elapsed: Duration,
+    /// The timings of every node that was executed, in the layout of [`LazyFrame::profile`]:
+    /// the `node` name with its `start` and `end` in microseconds since the start of the
+    /// execution.
+    ///
+    /// [`LazyFrame::profile`]: crate::frame::LazyFrame::profile
+    pub nodes: DataFrame,
+    /// The time spent in the policy monitor, which is the sum of the `monitor(..)` nodes.
+    pub monitor_time: Duration,
+    /// The uuids of the dataframes the monitor reported, in the order the nodes reported them.
+    /// The last one is the uuid of the result. Empty if policy checking is disabled.
+    pub lineage: Vec<Uuid>,
+}
+
+impl QueryMetrics {
+    pub(crate) fn new(
+        elapsed: Duration,
+        nodes: DataFrame,
+        lineage: Vec<Uuid>,
+    ) -> PolarsResult<Self> {
+        let names = nodes.column("node")?.str()?;
+        let start = nodes.column("start")?.u64()?;
+        let end = nodes.column("end")?.u64()?;
+        let monitor_micros = names
+            .into_iter()
+            .zip(start.into_no_null_iter().zip(end.into_no_null_iter()))
+            .filter(|(name, _)| name.map_or(false, |name| name.starts_with("monitor(")))
+            .map(|(_, (start, end))| end - start)
+            .sum();
+
+        Ok(Self {
+            elapsed,
+            nodes,
+            monitor_time: Duration::from_micros(monitor_micros),
+            lineage,
+        })
+    }
+}
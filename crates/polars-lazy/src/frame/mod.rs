@@ -5,15 +5,10 @@ mod python;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod metrics;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
-#[cfg(any(
-    feature = "parquet",
-    feature = "ipc",
-    feature = "csv",
-    feature = "json"
-))]
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -25,12 +20,12 @@ pub use exitable::*;
 pub use file_list_reader::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+pub use metrics::QueryMetrics;
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
-use picachv::native::{finalize, init_monitor, open_new};
-use picachv::PicachvError;
+use picachv::native::finalize;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
@@ -39,7 +34,7 @@ use smartstring::alias::String as SmartString;
 use uuid::Uuid;
 
 use crate::physical_plan::executors::Executor;
-use crate::physical_plan::planner::{create_physical_expr, create_physical_plan};
+use crate::physical_plan::planner::{create_physical_expr, create_physical_plan, explain_policy};
 use crate::physical_plan::state::ExecutionState;
 #[cfg(feature = "streaming")]
 use crate::physical_plan::streaming::insert_streaming_nodes;
@@ -79,6 +74,12 @@ pub struct LazyFrame {
     pub(crate) ctx_id: Uuid,
 }
 
+/// Run the final check of the monitor on the result `df_uuid` of the query `ctx_id`.
+fn finalize_query(ctx_id: Uuid, df_uuid: Uuid) -> PolarsResult<()> {
+    finalize(ctx_id, df_uuid)
+        .map_err(|err| PolarsError::from(err).with_policy_context("finalize", None))
+}
+
 impl From<DslPlan> for LazyFrame {
     fn from(plan: DslPlan) -> Self {
         Self {
@@ -128,6 +129,14 @@ impl LazyFrame {
         self.ctx_id
     }
 
+    /// Create a [`LazyFrame`] that scans `df` under `policy`.
+    ///
+    /// The policy is registered with the monitor when the query is collected with policy
+    /// checking enabled.
+    pub fn from_df_with_policy(df: DataFrame, policy: DataFramePolicy) -> Self {
+        df.with_policy(policy).lazy()
+    }
+
     pub fn set_policy_checking(mut self, enabled: bool) -> Self {
         self.policy_checking_enabled = enabled;
         self
@@ -162,6 +171,8 @@ impl LazyFrame {
             eager: false,
             fast_projection: false,
             row_estimate: false,
+            join_reorder: false,
+            policy_checking: false,
         })
     }
 
@@ -210,6 +221,10 @@ impl LazyFrame {
     }
 
     /// Run nodes that are capably of doing so on the streaming engine.
+    ///
+    /// With policy checking enabled, only the sources, filters and projections of a query run on
+    /// the streaming engine. Its group-by, join, sort and slice sinks do not report to the
+    /// monitor, so these nodes run on the default engine.
     pub fn with_streaming(mut self, toggle: bool) -> Self {
         self.opt_state.streaming = toggle;
         self
@@ -221,6 +236,13 @@ impl LazyFrame {
         self
     }
 
+    /// Reorder chains of inner joins, so that the relations with the fewest estimated rows are
+    /// joined first. This does not maintain the row order of the joins.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
@@ -275,6 +297,24 @@ impl LazyFrame {
         }
     }
 
+    /// Return a String describing how every node of the optimized plan is reported to the
+    /// policy monitor, which policy each scan registers and which nodes are refused when policy
+    /// checking is enabled. The plan is optimized as it is when policy checking is enabled, the
+    /// optimizations that are skipped for it are listed first.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn explain_policy(&self) -> PolarsResult<String> {
+        let mut expr_arena = Arena::with_capacity(64);
+        let mut lp_arena = Arena::with_capacity(64);
+        let lf = self.clone().set_policy_checking(true);
+        let opt_state = OptState {
+            policy_checking: true,
+            ..lf.opt_state
+        };
+        let lp_top = lf.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], true)?;
+        Ok(explain_policy(lp_top, &lp_arena, &opt_state))
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -561,9 +601,11 @@ impl LazyFrame {
         scratch: &mut Vec<Node>,
         _fmt: bool,
     ) -> PolarsResult<Node> {
-        #[allow(unused_mut)]
         let mut opt_state = self.opt_state;
+        opt_state.policy_checking = self.policy_checking_enabled;
         let streaming = self.opt_state.streaming;
+        #[cfg(feature = "streaming")]
+        let policy_ctx_id = self.policy_checking_enabled.then_some(self.ctx_id);
         #[cfg(feature = "cse")]
         if streaming && self.opt_state.comm_subplan_elim {
             polars_warn!(
@@ -602,6 +644,7 @@ impl LazyFrame {
                     _fmt,
                     true,
                     opt_state.row_estimate,
+                    policy_ctx_id,
                 )?;
             }
             #[cfg(not(feature = "streaming"))]
@@ -612,9 +655,8 @@ impl LazyFrame {
         Ok(lp_top)
     }
 
-    #[allow(unused_mut)]
     fn prepare_collect(
-        mut self,
+        self,
         check_sink: bool,
         ctx_id: Uuid,
         policy_check: bool,
@@ -659,34 +701,54 @@ impl LazyFrame {
     pub fn collect(self) -> PolarsResult<DataFrame> {
         let ctx_id = self.ctx_id;
         let policy_check = self.policy_checking_enabled;
-
         let (mut state, mut physical_plan, _) =
             self.prepare_collect(false, ctx_id, policy_check)?;
         state.set_ctx_id(ctx_id);
         state.set_policy_checking(policy_check);
 
         let now = std::time::Instant::now();
-        let df = match physical_plan.execute(&mut state) {
-            Ok(df) => df,
-            Err(e) => {
-                let elapsed = now.elapsed();
-                eprintln!("elapsed time: {:?}", elapsed);
-
-                return Err(e);
-            },
-        };
+        let df = physical_plan.execute(&mut state);
+        if state.verbose() {
+            eprintln!("elapsed time: {:?}", now.elapsed());
+        }
+        let df = df?;
 
         if policy_check {
-            finalize(ctx_id, state.get_active_df_uuid())
-                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+            finalize_query(ctx_id, state.get_active_df_uuid())?;
         }
 
-        let elapsed = now.elapsed();
-        println!("elapsed time: {:?}", elapsed);
-
         Ok(df)
     }
 
+    /// Execute all the lazy operations and collect them into a [`DataFrame`], together with the
+    /// [`QueryMetrics`] of the query: the time of the query and of every node, the time spent in
+    /// the policy monitor and the uuids of the dataframes the monitor reported.
+    ///
+    /// Nothing is measured or printed by [`LazyFrame::collect`], this is the opt-in to the
+    /// metrics.
+    pub fn collect_with_metrics(self) -> PolarsResult<(DataFrame, QueryMetrics)> {
+        let now = std::time::Instant::now();
+        let ctx_id = self.ctx_id;
+        let policy_check = self.policy_checking_enabled;
+        let (mut state, mut physical_plan, _) =
+            self.prepare_collect(false, ctx_id, policy_check)?;
+        state.set_ctx_id(ctx_id);
+        state.set_policy_checking(policy_check);
+        state.time_nodes();
+        state.record_lineage();
+        let out = physical_plan.execute(&mut state)?;
+        if policy_check {
+            state.record(
+                || finalize_query(ctx_id, state.get_active_df_uuid()),
+                "monitor(finalize)".into(),
+            )?;
+        }
+        let elapsed = now.elapsed();
+        let lineage = state.take_lineage();
+        let nodes = state.finish_timer()?;
+        Ok((out, QueryMetrics::new(elapsed, nodes, lineage)?))
+    }
+
     /// Profile a LazyFrame.
     ///
     /// This will run the query and return a tuple
@@ -694,20 +756,21 @@ impl LazyFrame {
     /// of each node that is executed.
     ///
     /// The units of the timings are microseconds.
+    ///
+    /// If policy checking is enabled, the time a node spends in the monitor is reported in a
+    /// separate `monitor(..)` node following it.
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
-        if let Err(e) = init_monitor() {
-            match e {
-                PicachvError::Already(_) => {},
-                _ => return Err(PolarsError::ComputeError(e.to_string().into())),
-            }
-        }
-
-        // Initialize a new context id.
-        let ctx_id = open_new().map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false, ctx_id, false)?;
+        let ctx_id = self.ctx_id;
+        let policy_check = self.policy_checking_enabled;
+        let (mut state, mut physical_plan, _) =
+            self.prepare_collect(false, ctx_id, policy_check)?;
         state.set_ctx_id(ctx_id);
+        state.set_policy_checking(policy_check);
         state.time_nodes();
         let out = physical_plan.execute(&mut state)?;
+        if policy_check {
+            finalize_query(ctx_id, state.get_active_df_uuid())?;
+        }
         let timer_df = state.finish_timer()?;
         Ok((out, timer_df))
     }
@@ -768,28 +831,19 @@ impl LazyFrame {
     /// streaming fashion.
     #[cfg(all(feature = "cloud_write", feature = "ipc"))]
     pub fn sink_ipc_cloud(
-        mut self,
+        self,
         uri: String,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
         ipc_options: IpcWriterOptions,
     ) -> PolarsResult<()> {
-        self.opt_state.streaming = true;
-        self.logical_plan = DslPlan::Sink {
-            input: Arc::new(self.logical_plan),
-            payload: SinkType::Cloud {
+        self.sink(
+            SinkType::Cloud {
                 uri: Arc::new(uri),
                 cloud_options,
                 file_type: FileType::Ipc(ipc_options),
             },
-        };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
-        polars_ensure!(
-            is_streaming,
-            ComputeError: "cannot run the whole query in a streaming order; \
-                           use `collect().write_ipc()` instead"
-        );
-        let _ = physical_plan.execute(&mut state)?;
-        Ok(())
+            "collect().write_ipc()",
+        )
     }
 
     /// Stream a query result into an csv file. This is useful if the final result doesn't fit
@@ -828,6 +882,14 @@ impl LazyFrame {
         feature = "json",
     ))]
     fn sink(mut self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
+        if self.policy_checking_enabled {
+            // The monitor validates the result as a whole and nothing may be written before it
+            // has passed, so the result is collected and only then streamed into the sink.
+            // The output carries no policy: the monitor cannot serialize the residual policy of
+            // a dataframe, so it is not written next to the output for a later scan to read.
+            let df = self.collect()?;
+            return df.lazy().sink(payload, msg_alternative);
+        }
         self.opt_state.streaming = true;
         self.logical_plan = DslPlan::Sink {
             input: Arc::new(self.logical_plan),
@@ -1117,6 +1179,35 @@ impl LazyFrame {
         self.join(other, vec![], vec![], JoinArgs::new(JoinType::Cross))
     }
 
+    /// Join this query with another lazy query on the rows for which all `predicates` hold.
+    ///
+    /// The predicates refer to the columns by their names after the join: a column of `other`
+    /// that is also a column of this query gets the `"_right"` suffix. Inequalities between the
+    /// two sides, such as `col("start").lt_eq(col("ts"))`, are evaluated by a range join, so
+    /// that the Cartesian product of both queries is never created. For control over the
+    /// suffix, use [`JoinBuilder::join_where`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn events_in_intervals(intervals: LazyFrame, events: LazyFrame) -> PolarsResult<LazyFrame> {
+    ///         intervals.join_where(
+    ///             events,
+    ///             [col("start").lt_eq(col("ts")), col("ts").lt(col("end"))],
+    ///         )
+    /// }
+    /// ```
+    #[cfg(feature = "range_join")]
+    pub fn join_where<E: AsRef<[Expr]>>(
+        self,
+        other: LazyFrame,
+        predicates: E,
+    ) -> PolarsResult<LazyFrame> {
+        self.join_builder().with(other).join_where(predicates)
+    }
+
     /// Left join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1950,4 +2041,41 @@ impl JoinBuilder {
             .build();
         LazyFrame::from_logical_plan(lp, opt_state)
     }
+
+    /// Finish the builder as a join on the rows for which all `predicates` hold, instead of a
+    /// join on keys. The predicates refer to the columns by their names after the join, so a
+    /// column of the right table that is also in the left table has the suffix.
+    ///
+    /// Up to two inequalities between a column of the left and a column of the right table are
+    /// evaluated by a range join, without creating the Cartesian product of both tables.
+    #[cfg(feature = "range_join")]
+    pub fn join_where<E: AsRef<[Expr]>>(self, predicates: E) -> PolarsResult<LazyFrame> {
+        let mut opt_state = self.lf.opt_state;
+        let other = self.other.expect("with not set");
+        opt_state.file_caching |= other.opt_state.file_caching;
+
+        let args = JoinArgs {
+            how: JoinType::Cross,
+            validation: self.validation,
+            suffix: self.suffix,
+            slice: None,
+            join_nulls: self.join_nulls,
+        };
+
+        let lp = self
+            .lf
+            .get_plan_builder()
+            .join_where(
+                other.logical_plan,
+                predicates.as_ref().to_vec(),
+                JoinOptions {
+                    allow_parallel: self.allow_parallel,
+                    force_parallel: self.force_parallel,
+                    args,
+                    ..Default::default()
+                },
+            )?
+            .build();
+        Ok(LazyFrame::from_logical_plan(lp, opt_state))
+    }
 }
@@ -1,5 +1,6 @@
 use picachv::native::execute_epilogue;
-use picachv::PlanArgument;
+use picachv::plan_argument::Argument;
+use picachv::{PlanArgument, TransformArgument, TransformInfo};
 
 use super::*;
 
@@ -25,8 +26,19 @@ pub trait Executor: Send {
         //     plan_arg,
         // );
 
-        let active_df_uuid = execute_epilogue(cache.ctx_id, cache.active_df_uuid, plan_arg)
-            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let node_name = monitor_node_name(plan_arg.as_ref());
+        let profile_name = if cache.has_node_timer() {
+            Cow::Owned(format!("monitor({node_name})"))
+        } else {
+            Cow::Borrowed("")
+        };
+        let active_df_uuid = cache
+            .record(
+                || execute_epilogue(cache.ctx_id, cache.active_df_uuid, plan_arg),
+                profile_name,
+            )
+            .map_err(|err| PolarsError::from(err).with_policy_context(node_name, None))?;
+        cache.push_lineage(active_df_uuid);
         cache.set_active_df_uuid(active_df_uuid);
         cache.transform.take();
 
@@ -34,6 +46,28 @@ pub trait Executor: Send {
     }
 }
 
+/// The name under which the time spent in the monitor is profiled.
+fn monitor_node_name(plan_arg: Option<&PlanArgument>) -> &'static str {
+    match plan_arg.and_then(|arg| arg.argument.as_ref()) {
+        Some(Argument::GetData(_)) => "scan",
+        Some(Argument::Select(_)) => "filter",
+        Some(Argument::Projection(_)) => "select",
+        Some(Argument::Hstack(_)) => "with_columns",
+        Some(Argument::Aggregate(_)) => "group_by",
+        Some(Argument::Transform(_)) => "transform",
+        None => "epilogue",
+    }
+}
+
+/// The argument reported by an executor that keeps the rows of its input that are set in `mask`,
+/// in their original order.
+pub(super) fn filter_plan_argument(mask: &[bool]) -> PolarsResult<PlanArgument> {
+    Ok(PlanArgument {
+        argument: Some(Argument::Transform(TransformArgument {})),
+        transform_info: Some(TransformInfo::from_filter(mask)?),
+    })
+}
+
 pub struct Dummy {}
 impl Executor for Dummy {
     fn execute(&mut self, _cache: &mut ExecutionState) -> PolarsResult<DataFrame> {
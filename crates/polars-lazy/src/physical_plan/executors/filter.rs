@@ -56,10 +56,9 @@ impl FilterExec {
 
         let df = df.filter(pred)?;
 
-        state.transform.replace(
-            TransformInfo::from_filter(&pred_bool)
-                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?,
-        );
+        state
+            .transform
+            .replace(TransformInfo::from_filter(&pred_bool)?);
 
         Ok(df)
     }
@@ -89,10 +88,9 @@ impl FilterExec {
             .map(|(_, pred)| pred.clone())
             .flatten()
             .collect::<Vec<_>>();
-        state.transform.replace(
-            TransformInfo::from_filter(&pred_bool)
-                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?,
-        );
+        state
+            .transform
+            .replace(TransformInfo::from_filter(&pred_bool)?);
         Ok(accumulate_dataframes_vertical_unchecked(df))
     }
 
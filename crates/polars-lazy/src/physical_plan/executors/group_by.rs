@@ -5,6 +5,87 @@ use picachv::{AggregateArgument, GroupByIdx, GroupByProxy, PlanArgument};
 
 use super::*;
 
+/// The groups of a group-by as reported to the monitor.
+///
+/// The group tuples cannot be handed over as they are: `GroupByIdx` is the only kind of groups the
+/// monitor accepts, and it owns its indices as `Vec<u64>`. The `IdxVec`s of `Idx` groups hold
+/// `IdxSize` indices in their own storage, so their indices are copied, and `Slice` groups are
+/// expanded into the row indices they cover. This is done once per group-by and only when the
+/// policy is checked.
+pub(super) fn group_by_proxy(groups: &GroupsProxy) -> GroupByProxy {
+    let groups = match groups {
+        GroupsProxy::Idx(groups) => groups
+            .iter()
+            .map(|(first, group)| Groups {
+                first: first as u64,
+                group: group.iter().map(|&i| i as u64).collect(),
+            })
+            .collect(),
+        GroupsProxy::Slice { groups, .. } => groups
+            .iter()
+            .map(|&[first, len]| Groups {
+                first: first as u64,
+                group: (first as u64..(first + len) as u64).collect(),
+            })
+            .collect(),
+    };
+
+    GroupByProxy {
+        group_by: Some(GroupBy::GroupByIdx(GroupByIdx { groups })),
+    }
+}
+
+/// The aggregation of a group-by as reported to the monitor.
+pub(super) fn aggregate_plan_argument(
+    keys: &[Arc<dyn PhysicalExpr>],
+    aggs: &[Arc<dyn PhysicalExpr>],
+    group_by_proxy: Option<GroupByProxy>,
+    maintain_order: bool,
+    output_schema: &Schema,
+    state: &ExecutionState,
+) -> PlanArgument {
+    PlanArgument {
+        argument: Some(Argument::Aggregate(AggregateArgument {
+            keys: keys
+                .iter()
+                .map(|e| e.get_uuid().to_bytes_le().to_vec())
+                .collect(),
+            aggs_uuid: aggs
+                .iter()
+                .map(|e| e.get_uuid().to_bytes_le().to_vec())
+                .collect(),
+            maintain_order,
+            group_by_proxy,
+            output_schema: output_schema
+                .get_names()
+                .into_iter()
+                .map(|s| s.to_string())
+                .collect(),
+        })),
+        transform_info: state.transform.clone(),
+    }
+}
+
+/// The aggregation of a group-by over windows (`group_by_dynamic`, `rolling`) as reported to the
+/// monitor. The windows are given by `groups`, the output is ordered by the windows.
+#[cfg(feature = "dynamic_group_by")]
+pub(super) fn window_aggregate_plan_argument(
+    keys: &[Arc<dyn PhysicalExpr>],
+    aggs: &[Arc<dyn PhysicalExpr>],
+    groups: &GroupsProxy,
+    input_schema: &Schema,
+    state: &ExecutionState,
+) -> PlanArgument {
+    aggregate_plan_argument(
+        keys,
+        aggs,
+        Some(group_by_proxy(groups)),
+        true,
+        input_schema,
+        state,
+    )
+}
+
 pub(super) fn evaluate_aggs(
     df: &DataFrame,
     aggs: &[Arc<dyn PhysicalExpr>],
@@ -65,36 +146,28 @@ impl GroupByExec {
     }
 }
 
+/// Run the aggregation of a group-by. If policy checking is enabled, the groups the output rows
+/// are aggregated from are returned as well.
 #[allow(clippy::too_many_arguments)]
 pub(super) fn group_by_helper(
     mut df: DataFrame,
     keys: Vec<Series>,
     aggs: &[Arc<dyn PhysicalExpr>],
     apply: Option<Arc<dyn DataFrameUdf>>,
-    state: &mut ExecutionState,
+    state: &ExecutionState,
     maintain_order: bool,
     slice: Option<(i64, usize)>,
-) -> PolarsResult<DataFrame> {
+) -> PolarsResult<(DataFrame, Option<GroupByProxy>)> {
     df.as_single_chunk_par();
     let gb = df.group_by_with_series(keys, true, maintain_order)?;
 
     // todo: add a check here? We may need it.
     if let Some(f) = apply {
-        return gb.apply(move |df| f.call_udf(df));
+        return Ok((gb.apply(move |df| f.call_udf(df))?, None));
     }
 
     let mut groups = gb.get_groups();
 
-    // Do not clone here since doing so will cause data corruption (we don't know why).
-    if let GroupsProxy::Idx(idx) = groups {
-        state.last_used_groupby.0 = idx.first().iter().map(|&e| e as u64).collect();
-        state.last_used_groupby.1 = idx
-            .all()
-            .iter()
-            .map(|e| e.iter().map(|&e| e as u64).collect())
-            .collect();
-    }
-
     #[allow(unused_assignments)]
     // it is unused because we only use it to keep the lifetime of sliced_group valid
     let mut sliced_groups = None;
@@ -116,9 +189,10 @@ pub(super) fn group_by_helper(
         evaluate_aggs(&df, aggs, groups, state),
     );
     let agg_columns = agg_columns?;
+    let proxy = state.policy_check.then(|| group_by_proxy(groups));
 
     columns.extend_from_slice(&agg_columns);
-    DataFrame::new(columns)
+    Ok((DataFrame::new(columns)?, proxy))
 }
 
 impl GroupByExec {
@@ -126,7 +200,7 @@ impl GroupByExec {
         &mut self,
         state: &mut ExecutionState,
         df: DataFrame,
-    ) -> PolarsResult<DataFrame> {
+    ) -> PolarsResult<(DataFrame, Option<GroupByProxy>)> {
         let keys = self
             .keys
             .iter()
@@ -170,7 +244,7 @@ impl Executor for GroupByExec {
             Cow::Borrowed("")
         };
 
-        let df = if state.has_node_timer() {
+        let (df, proxy) = if state.has_node_timer() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
@@ -178,43 +252,14 @@ impl Executor for GroupByExec {
         }?;
 
         if state.policy_check {
-            let gb = Some(state.last_used_groupby.clone());
-            let plan_arg = PlanArgument {
-                argument: Some(Argument::Aggregate(AggregateArgument {
-                    keys: self
-                        .keys
-                        .iter()
-                        .map(|e| e.get_uuid().to_bytes_le().to_vec())
-                        .collect(),
-                    aggs_uuid: self
-                        .aggs
-                        .iter()
-                        .map(|e| e.get_uuid().to_bytes_le().to_vec())
-                        .collect(),
-                    maintain_order: self.maintain_order,
-                    group_by_proxy: gb.map(|gb| GroupByProxy {
-                        group_by: Some(GroupBy::GroupByIdx(GroupByIdx {
-                            groups: gb
-                                .0
-                                .iter()
-                                .zip(gb.1.iter())
-                                .map(|e| Groups {
-                                    first: *e.0 as u64,
-                                    group: e.1.iter().cloned().collect(),
-                                })
-                                .collect(),
-                        })),
-                    }),
-                    output_schema: self
-                        .input_schema
-                        .get_names()
-                        .into_iter()
-                        .map(|s| s.to_string())
-                        .collect(),
-                })),
-                transform_info: state.transform.clone(),
-            };
-
+            let plan_arg = aggregate_plan_argument(
+                &self.keys,
+                &self.aggs,
+                proxy,
+                self.maintain_order,
+                &self.input_schema,
+                state,
+            );
             self.execute_epilogue(state, Some(plan_arg))?;
         }
 
@@ -1,7 +1,4 @@
-use picachv::group_by_idx::Groups;
-use picachv::group_by_proxy::GroupBy;
-use picachv::plan_argument::Argument;
-use picachv::{AggregateArgument, GroupByIdx, GroupByProxy, PlanArgument};
+use picachv::GroupByProxy;
 use polars_core::series::IsSorted;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use rayon::prelude::*;
@@ -255,7 +252,7 @@ impl PartitionGroupByExec {
         &mut self,
         state: &mut ExecutionState,
         mut original_df: DataFrame,
-    ) -> PolarsResult<DataFrame> {
+    ) -> PolarsResult<(DataFrame, Option<GroupByProxy>)> {
         let (splitted_dfs, splitted_keys) = {
             // already get the keys. This is the very last minute decision which group_by method we choose.
             // If the column is a categorical, we know the number of groups we have and can decide to continue
@@ -328,15 +325,6 @@ impl PartitionGroupByExec {
             groups = sliced_groups.as_deref().unwrap();
         }
 
-        if let GroupsProxy::Idx(idx) = groups {
-            state.last_used_groupby.0 = idx.first().iter().map(|&e| e as u64).collect();
-            state.last_used_groupby.1 = idx
-                .all()
-                .iter()
-                .map(|e| e.iter().map(|&e| e as u64).collect())
-                .collect();
-        }
-
         let get_columns = || gb.keys_sliced(self.slice);
         let get_agg = || {
             let out: PolarsResult<Vec<_>> = self
@@ -356,8 +344,9 @@ impl PartitionGroupByExec {
 
         columns.extend(agg_columns?);
         state.clear_schema_cache();
+        let proxy = state.policy_check.then(|| group_by_proxy(groups));
 
-        Ok(DataFrame::new(columns).unwrap())
+        Ok((DataFrame::new(columns).unwrap(), proxy))
     }
 }
 
@@ -384,7 +373,7 @@ impl Executor for PartitionGroupByExec {
             Cow::Borrowed("")
         };
 
-        let df = if state.has_node_timer() {
+        let (df, proxy) = if state.has_node_timer() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, original_df), profile_name)
         } else {
@@ -392,43 +381,14 @@ impl Executor for PartitionGroupByExec {
         }?;
 
         if state.policy_check {
-            let gb = Some(state.last_used_groupby.clone());
-            let plan_arg = PlanArgument {
-                argument: Some(Argument::Aggregate(AggregateArgument {
-                    keys: self
-                        .phys_keys
-                        .par_iter()
-                        .map(|e| e.get_uuid().to_bytes_le().to_vec())
-                        .collect(),
-                    aggs_uuid: self
-                        .phys_aggs
-                        .par_iter()
-                        .map(|e| e.get_uuid().to_bytes_le().to_vec())
-                        .collect(),
-                    maintain_order: self.maintain_order,
-                    group_by_proxy: gb.map(|gb| GroupByProxy {
-                        group_by: Some(GroupBy::GroupByIdx(GroupByIdx {
-                            groups: gb
-                                .0
-                                .iter()
-                                .zip(gb.1.iter())
-                                .map(|e| Groups {
-                                    first: *e.0 as u64,
-                                    group: e.1.iter().cloned().collect(),
-                                })
-                                .collect(),
-                        })),
-                    }),
-                    output_schema: self
-                        .output_schema
-                        .get_names()
-                        .into_par_iter()
-                        .map(|s| s.to_string())
-                        .collect(),
-                })),
-                transform_info: state.transform.clone(),
-            };
-
+            let plan_arg = aggregate_plan_argument(
+                &self.phys_keys,
+                &self.phys_aggs,
+                proxy,
+                self.maintain_order,
+                &self.output_schema,
+                state,
+            );
             self.execute_epilogue(state, Some(plan_arg))?;
         }
 
@@ -1,3 +1,6 @@
+#[cfg(feature = "dynamic_group_by")]
+use picachv::PlanArgument;
+
 use super::*;
 
 #[cfg_attr(not(feature = "dynamic_group_by"), allow(dead_code))]
@@ -39,7 +42,7 @@ impl GroupByRollingExec {
         &mut self,
         state: &ExecutionState,
         mut df: DataFrame,
-    ) -> PolarsResult<DataFrame> {
+    ) -> PolarsResult<(DataFrame, Option<PlanArgument>)> {
         df.as_single_chunk_par();
 
         let keys = self
@@ -53,11 +56,14 @@ impl GroupByRollingExec {
         if let Some(f) = &self.apply {
             let gb = GroupBy::new(&df, vec![], groups, None);
             let out = gb.apply(move |df| f.call_udf(df))?;
-            return Ok(if let Some((offset, len)) = self.slice {
-                out.slice(offset, len)
-            } else {
-                out
-            });
+            return Ok((
+                if let Some((offset, len)) = self.slice {
+                    out.slice(offset, len)
+                } else {
+                    out
+                },
+                None,
+            ));
         }
 
         let mut groups = &groups;
@@ -78,13 +84,22 @@ impl GroupByRollingExec {
         };
 
         let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state)?;
+        let plan_arg = state.policy_check.then(|| {
+            window_aggregate_plan_argument(
+                &self.keys,
+                &self.aggs,
+                groups,
+                &self.input_schema,
+                state,
+            )
+        });
 
         let mut columns = Vec::with_capacity(agg_columns.len() + 1 + keys.len());
         columns.extend_from_slice(&keys);
         columns.push(time_key);
         columns.extend_from_slice(&agg_columns);
 
-        DataFrame::new(columns)
+        Ok((DataFrame::new(columns)?, plan_arg))
     }
 }
 
@@ -116,11 +131,17 @@ impl Executor for GroupByRollingExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        let (df, plan_arg) = if state.has_node_timer() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
+        }?;
+
+        if let Some(plan_arg) = plan_arg {
+            self.execute_epilogue(state, Some(plan_arg))?;
         }
+
+        Ok(df)
     }
 }
@@ -1,4 +1,10 @@
+use picachv::plan_argument::Argument;
+use picachv::transform_info::Information;
+use picachv::{
+    JoinInformation, PlanArgument, RowJoinInformation, TransformArgument, TransformInfo,
+};
 use polars_core::functions::concat_df_horizontal;
+use uuid::Uuid;
 
 use super::*;
 
@@ -28,7 +34,7 @@ impl Executor for HConcatExec {
 
                 let df = input.execute(&mut state)?;
 
-                dfs.push(df);
+                dfs.push((df, state.active_df_uuid));
             }
             dfs
         } else {
@@ -49,7 +55,7 @@ impl Executor for HConcatExec {
                                 let mut input = std::mem::take(input);
                                 let mut state = state.split();
                                 state.branch_idx += idx;
-                                input.execute(&mut state)
+                                Ok((input.execute(&mut state)?, state.active_df_uuid))
                             })
                             .collect::<PolarsResult<Vec<_>>>()
                     })
@@ -58,6 +64,58 @@ impl Executor for HConcatExec {
             out?.into_iter().flatten().collect()
         };
 
-        concat_df_horizontal(&dfs)
+        let (dfs, df_uuids): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+        let out = concat_df_horizontal(&dfs)?;
+
+        if state.policy_check {
+            self.report_policy(state, &dfs, &df_uuids)?;
+        }
+
+        Ok(out)
+    }
+}
+
+impl HConcatExec {
+    /// Report the concatenation to the monitor as a chain of positional joins.
+    fn report_policy(
+        &self,
+        state: &mut ExecutionState,
+        dfs: &[DataFrame],
+        df_uuids: &[Uuid],
+    ) -> PolarsResult<()> {
+        let height = dfs[0].height();
+        polars_ensure!(
+            dfs.iter().all(|df| df.height() == height),
+            InvalidOperation: "'hconcat' of frames with different heights is not supported when policy checking is enabled"
+        );
+
+        state.set_active_df_uuid(df_uuids[0]);
+        let mut lhs_width = dfs[0].width();
+        for (rhs, rhs_df_uuid) in dfs.iter().zip(df_uuids).skip(1) {
+            let ti = JoinInformation {
+                lhs_df_uuid: state.active_df_uuid.to_bytes_le().to_vec(),
+                rhs_df_uuid: rhs_df_uuid.to_bytes_le().to_vec(),
+                row_join_info: (0..height as u64)
+                    .map(|i| RowJoinInformation {
+                        left_row: i,
+                        right_row: i,
+                    })
+                    .collect(),
+                left_columns: (0..lhs_width as u64).collect(),
+                right_columns: (0..rhs.width() as u64).collect(),
+                renaming_info: vec![],
+            };
+            let arg = PlanArgument {
+                argument: Some(Argument::Transform(TransformArgument {})),
+                transform_info: Some(TransformInfo {
+                    information: Some(Information::Join(ti)),
+                }),
+            };
+
+            self.execute_epilogue(state, Some(arg))?;
+            lhs_width += rhs.width();
+        }
+
+        Ok(())
     }
 }
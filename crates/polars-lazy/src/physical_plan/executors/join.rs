@@ -4,6 +4,9 @@ use uuid::Uuid;
 
 use super::*;
 
+/// The row index that a semi or anti join adds to the left frame to find the rows it keeps.
+const POLICY_ROW_INDEX: &str = "__POLARS_POLICY_ROW_INDEX";
+
 pub struct JoinExec {
     input_left: Option<Box<dyn Executor>>,
     input_right: Option<Box<dyn Executor>>,
@@ -101,7 +104,7 @@ impl Executor for JoinExec {
             Cow::Borrowed("")
         };
 
-        let (df, ti) = state.record(|| {
+        let (df, ti, mask) = state.record(|| {
             let left_on_series = self
                 .left_on
                 .iter()
@@ -115,11 +118,18 @@ impl Executor for JoinExec {
                 .collect::<PolarsResult<Vec<_>>>()?;
 
             // make sure that we can join on evaluated expressions
-            for s in &left_on_series {
-                df_left.with_column(s.clone())?;
-            }
-            for s in &right_on_series {
-                df_right.with_column(s.clone())?;
+            // a range join takes the keys as is and keeps the columns of both sides unchanged
+            #[cfg(feature = "range_join")]
+            let keys_as_columns = !matches!(self.args.how, JoinType::Range(_));
+            #[cfg(not(feature = "range_join"))]
+            let keys_as_columns = true;
+            if keys_as_columns {
+                for s in &left_on_series {
+                    df_left.with_column(s.clone())?;
+                }
+                for s in &right_on_series {
+                    df_right.with_column(s.clone())?;
+                }
             }
 
             // prepare the tolerance
@@ -162,6 +172,18 @@ impl Executor for JoinExec {
                 }
             }
 
+            // A semi or anti join only keeps rows of the left frame, so the monitor sees it as a
+            // filter of the left side. The kept rows are found through a row index.
+            #[cfg(feature = "semi_anti_join")]
+            let filters_left = state.policy_check
+                && matches!(self.args.how, JoinType::Semi | JoinType::Anti);
+            #[cfg(not(feature = "semi_anti_join"))]
+            let filters_left = false;
+            let left_height = df_left.height();
+            if filters_left {
+                df_left.with_row_index_mut(POLICY_ROW_INDEX, None);
+            }
+
             let mut ti = JoinInformation::default();
             ti.lhs_df_uuid = lhs_df_uuid.to_bytes_le().to_vec();
             ti.rhs_df_uuid = rhs_df_uuid.to_bytes_le().to_vec();
@@ -181,7 +203,19 @@ impl Executor for JoinExec {
             };
             
             match df {
-                Ok(df) => Ok((df, ti)),
+                Ok(mut df) => {
+                    let mask = if filters_left {
+                        let mut mask = vec![false; left_height];
+                        for i in df.column(POLICY_ROW_INDEX)?.idx()?.into_no_null_iter() {
+                            mask[i as usize] = true;
+                        }
+                        df = df.drop(POLICY_ROW_INDEX)?;
+                        Some(mask)
+                    } else {
+                        None
+                    };
+                    Ok((df, ti, mask))
+                },
                 Err(e) => {
                     if state.verbose() {
                         eprintln!("{:?}", e)
@@ -193,11 +227,25 @@ impl Executor for JoinExec {
         }, profile_name)?;
 
         if state.policy_check {
+            let transform_info = match mask {
+                Some(mask) => {
+                    state.set_active_df_uuid(lhs_df_uuid);
+                    TransformInfo::from_filter(&mask)?
+                },
+                None => {
+                    // The monitor has no encoding for a row without a match.
+                    #[cfg(feature = "asof_join")]
+                    polars_ensure!(
+                        !matches!(self.args.how, JoinType::AsOf(_))
+                            || ti.row_join_info.len() == df.height(),
+                        InvalidOperation: "cannot check an asof join with unmatched rows against a policy"
+                    );
+                    TransformInfo { information: Some(Information::Join(ti)) }
+                },
+            };
             let arg = PlanArgument {
                 argument: Some(Argument::Transform(TransformArgument {})),
-                transform_info: Some(TransformInfo { information: Some(
-                    Information::Join(ti)
-                ) }),
+                transform_info: Some(transform_info),
             };
             
             self.execute_epilogue(state, Some(arg))?;
@@ -45,6 +45,10 @@ pub(super) use self::projection::*;
 pub(super) use self::projection_simple::*;
 #[cfg(feature = "python")]
 pub(super) use self::python_scan::*;
+#[cfg(test)]
+pub(crate) use self::scan::limit_scan_rows;
+#[cfg(all(test, feature = "ipc"))]
+pub(crate) use self::scan::register_file_policies;
 pub(super) use self::scan::*;
 pub(super) use self::slice::*;
 pub(super) use self::sort::*;
@@ -8,10 +8,14 @@ pub struct CsvExec {
     pub options: CsvParserOptions,
     pub file_options: FileScanOptions,
     pub predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub with_policy: Option<Arc<ScanPolicy>>,
 }
 
 impl CsvExec {
-    fn read(&mut self) -> PolarsResult<DataFrame> {
+    /// Read the file. If `policy_check` is set, the file is read in full and `n_rows` and the
+    /// predicate are applied after reading, as the monitor needs to know which rows of the file
+    /// survived. The returned mask then covers every row of the file.
+    fn read(&mut self, policy_check: bool) -> PolarsResult<(DataFrame, Option<BooleanChunked>)> {
         let with_columns = self
             .file_options
             .with_columns
@@ -22,8 +26,18 @@ impl CsvExec {
 
         let n_rows = _set_n_rows_for_scan(self.file_options.n_rows);
         let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
+        let (n_rows, post_n_rows) = if policy_check {
+            (None, n_rows)
+        } else {
+            (n_rows, None)
+        };
+        let (predicate, post_predicate) = if policy_check {
+            (None, predicate)
+        } else {
+            (predicate, None)
+        };
 
-        CsvReader::from_path(&self.path)
+        let mut df = CsvReader::from_path(&self.path)
             .unwrap()
             .has_header(self.options.has_header)
             .with_dtypes(Some(self.schema.clone()))
@@ -47,12 +61,20 @@ impl CsvExec {
             .truncate_ragged_lines(self.options.truncate_ragged_lines)
             .with_decimal_float(self.options.decimal_float)
             .raise_if_empty(self.options.raise_if_empty)
-            .finish()
+            .finish()?;
+
+        let mask = limit_scan_rows(&mut df, post_n_rows, |df| {
+            polars_io::predicates::apply_predicate(df, post_predicate.as_deref(), true)
+        })?;
+        Ok((df, mask))
     }
 }
 
 impl Executor for CsvExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        polars_ensure!(!(state.policy_check && self.with_policy.is_none()),
+            InvalidOperation: "Policy check requested but no policy was provided");
+
         let profile_name = if state.has_node_timer() {
             let mut ids = vec![self.path.to_string_lossy().into()];
             if self.predicate.is_some() {
@@ -64,6 +86,30 @@ impl Executor for CsvExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| self.read(), profile_name)
+        // `read` takes the projected columns, so keep them around for the monitor.
+        let with_columns = self
+            .file_options
+            .with_columns
+            .clone()
+            .filter(|columns| !columns.is_empty());
+        let has_row_index = self.file_options.row_index.is_some();
+        let (df, mask) = state.record(|| self.read(state.policy_check), profile_name)?;
+
+        if state.policy_check {
+            let projection = scan_projection(with_columns.as_ref(), &self.schema, has_row_index);
+            let df_uuid = register_scan_policy(
+                state.ctx_id,
+                &self.with_policy.as_ref().unwrap().resolve(&self.path)?,
+                &projection,
+                mask.as_ref(),
+            )?;
+            state.set_active_df_uuid(df_uuid);
+
+            let project_list = scan_project_list(with_columns.as_ref(), &df)?;
+            let plan_arg = scan_plan_argument(state, project_list);
+            self.execute_epilogue(state, Some(plan_arg))?;
+        }
+
+        Ok(df)
     }
 }
@@ -19,12 +19,36 @@ pub struct IpcExec {
     pub(crate) file_options: FileScanOptions,
     pub(crate) cloud_options: Option<CloudOptions>,
     pub(crate) metadata: Option<arrow::io::ipc::read::FileMetadata>,
+    pub(crate) with_policy: Option<Arc<ScanPolicy>>,
 }
 
 impl IpcExec {
-    fn read(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
+    /// Read the files. Next to the predicate mask, the number of rows read from every file is
+    /// returned, in the order of the files.
+    ///
+    /// If `policy_check` is set, the files are read in full and `n_rows` and the predicate are
+    /// applied after reading, as the monitor needs to know which rows of the files survived. The
+    /// returned mask then covers every row of the files.
+    #[allow(clippy::type_complexity)]
+    fn read(
+        &mut self,
+        verbose: bool,
+        policy_check: bool,
+    ) -> PolarsResult<(DataFrame, Option<BooleanChunked>, Vec<usize>)> {
+        let (n_rows, post_n_rows) = if policy_check {
+            (None, self.file_options.n_rows)
+        } else {
+            (self.file_options.n_rows, None)
+        };
+        let predicate = self.predicate.clone();
+        let (predicate, post_predicate) = if policy_check {
+            (None, predicate.map(phys_expr_to_io_expr))
+        } else {
+            (predicate, None)
+        };
+
         let is_cloud = self.paths.iter().any(is_cloud_url);
-        let mut out = if is_cloud || config::force_async() {
+        let (mut out, mask, file_heights) = if is_cloud || config::force_async() {
             #[cfg(not(feature = "cloud"))]
             {
                 panic!("activate cloud feature")
@@ -36,26 +60,41 @@ impl IpcExec {
                     eprintln!("ASYNC READING FORCED");
                 }
 
-                polars_io::pl_async::get_runtime()
-                    .block_on_potential_spawn(self.read_async(verbose))?
+                polars_io::pl_async::get_runtime().block_on_potential_spawn(self.read_async(
+                    verbose,
+                    n_rows,
+                    predicate.as_ref(),
+                ))?
             }
         } else {
-            self.read_sync()?
+            self.read_sync(n_rows, predicate.as_ref())?
+        };
+        let mask = if policy_check {
+            limit_scan_rows(&mut out, post_n_rows, |df| {
+                apply_predicate(df, post_predicate.as_deref(), true)
+            })?
+        } else {
+            mask
         };
 
         if self.file_options.rechunk {
             out.as_single_chunk_par();
         }
 
-        Ok(out)
+        Ok((out, mask, file_heights))
     }
 
-    fn read_sync(&mut self) -> PolarsResult<DataFrame> {
+    #[allow(clippy::type_complexity)]
+    fn read_sync(
+        &mut self,
+        n_rows: Option<usize>,
+        predicate: Option<&Arc<dyn PhysicalExpr>>,
+    ) -> PolarsResult<(DataFrame, Option<BooleanChunked>, Vec<usize>)> {
         if config::verbose() {
             eprintln!("executing ipc read sync with row_index = {:?}, n_rows = {:?}, predicate = {:?} for paths {:?}",
                 self.file_options.row_index.as_ref(),
-                self.file_options.n_rows.as_ref(),
-                self.predicate.is_some(),
+                n_rows.as_ref(),
+                predicate.is_some(),
                 self.paths
             );
         }
@@ -70,10 +109,7 @@ impl IpcExec {
             self.file_options.row_index.is_some(),
         );
 
-        let n_rows = self
-            .file_options
-            .n_rows
-            .map(|n| IdxSize::try_from(n).unwrap());
+        let n_rows = n_rows.map(|n| IdxSize::try_from(n).unwrap());
 
         let row_limit = n_rows.unwrap_or(IdxSize::MAX);
 
@@ -124,12 +160,18 @@ impl IpcExec {
             row_counter.into_inner().unwrap(),
             self.file_options.row_index.as_ref(),
             row_limit,
-            self.predicate.as_ref(),
+            predicate,
         )
     }
 
     #[cfg(feature = "cloud")]
-    async fn read_async(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
+    #[allow(clippy::type_complexity)]
+    async fn read_async(
+        &mut self,
+        verbose: bool,
+        n_rows: Option<usize>,
+        predicate: Option<&Arc<dyn PhysicalExpr>>,
+    ) -> PolarsResult<(DataFrame, Option<BooleanChunked>, Vec<usize>)> {
         use futures::stream::{self, StreamExt};
         use futures::TryStreamExt;
 
@@ -145,10 +187,7 @@ impl IpcExec {
 
         impl<T: Send + stream::Stream + Sized> AssertSend for T {}
 
-        let n_rows = self
-            .file_options
-            .n_rows
-            .map(|limit| limit.try_into().unwrap());
+        let n_rows = n_rows.map(|limit| limit.try_into().unwrap());
 
         let row_limit = n_rows.unwrap_or(IdxSize::MAX);
 
@@ -211,18 +250,21 @@ impl IpcExec {
             row_counter.into_inner().unwrap(),
             self.file_options.row_index.as_ref(),
             row_limit,
-            self.predicate.as_ref(),
+            predicate,
         )
     }
 }
 
+/// Combine the dataframes read from the files, in order. Returns the predicate mask and the
+/// number of rows taken from every file next to the combined dataframe.
+#[allow(clippy::type_complexity)]
 fn finish_index_and_dfs(
     mut index_and_dfs: Vec<(usize, DataFrame)>,
     row_counter: ConsecutiveCountState,
     row_index: Option<&RowIndex>,
     row_limit: IdxSize,
     predicate: Option<&Arc<dyn PhysicalExpr>>,
-) -> PolarsResult<DataFrame> {
+) -> PolarsResult<(DataFrame, Option<BooleanChunked>, Vec<usize>)> {
     index_and_dfs.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
 
     debug_assert!(
@@ -232,6 +274,7 @@ fn finish_index_and_dfs(
 
     debug_assert_eq!(index_and_dfs.len(), row_counter.len());
     let mut offset = 0;
+    let mut file_heights = Vec::with_capacity(row_counter.len());
     let mut df = accumulate_dataframes_vertical(
         index_and_dfs
             .into_iter()
@@ -255,19 +298,23 @@ fn finish_index_and_dfs(
                 }
 
                 offset += count;
+                file_heights.push(df.height());
 
                 Some(df)
             }),
     )?;
 
     let predicate = predicate.cloned().map(phys_expr_to_io_expr);
-    apply_predicate(&mut df, predicate.as_deref(), true)?;
+    let mask = apply_predicate(&mut df, predicate.as_deref(), true)?;
 
-    Ok(df)
+    Ok((df, mask, file_heights))
 }
 
 impl Executor for IpcExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        polars_ensure!(!(state.policy_check && self.with_policy.is_none()),
+            InvalidOperation: "Policy check requested but no policy was provided");
+
         let profile_name = if state.has_node_timer() {
             let mut ids = vec![self.paths[0].to_string_lossy().into()];
             if self.predicate.is_some() {
@@ -279,6 +326,32 @@ impl Executor for IpcExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| self.read(state.verbose()), profile_name)
+        let (df, mask, file_heights) = state.record(
+            || self.read(state.verbose(), state.policy_check),
+            profile_name,
+        )?;
+
+        if state.policy_check {
+            let projection = scan_projection(
+                self.file_options.with_columns.as_ref(),
+                &self.schema,
+                self.file_options.row_index.is_some(),
+            );
+            let df_uuid = register_file_policies(
+                state.ctx_id,
+                self.with_policy.as_ref().unwrap(),
+                &self.paths,
+                &file_heights,
+                mask.as_ref(),
+                |policy, mask| register_scan_policy(state.ctx_id, policy, &projection, mask),
+            )?;
+            state.set_active_df_uuid(df_uuid);
+
+            let project_list = scan_project_list(self.file_options.with_columns.as_ref(), &df)?;
+            let plan_arg = scan_plan_argument(state, project_list);
+            self.execute_epilogue(state, Some(plan_arg))?;
+        }
+
+        Ok(df)
     }
 }
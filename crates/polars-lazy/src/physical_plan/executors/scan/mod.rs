@@ -12,6 +12,9 @@ mod support;
 use std::mem;
 #[cfg(any(feature = "parquet", feature = "ipc", feature = "cse"))]
 use std::ops::Deref;
+use std::path::Path;
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+use std::path::PathBuf;
 
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvExec;
@@ -21,6 +24,11 @@ pub(crate) use ipc::IpcExec;
 pub(crate) use parquet::ParquetExec;
 use picachv::get_data_argument::DataSource;
 use picachv::get_data_in_memory::ProjectList;
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+use picachv::native::execute_epilogue;
+use picachv::native::register_policy_dataframe_parquet;
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+use picachv::TransformArgument;
 use picachv::{plan_argument, GetDataArgument, GetDataInMemory, PlanArgument, TransformInfo};
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 use polars_io::predicates::PhysicalIoExpr;
@@ -29,9 +37,10 @@ use polars_io::prelude::*;
 use polars_plan::global::_set_n_rows_for_scan;
 #[cfg(feature = "ipc")]
 pub(crate) use support::ConsecutiveCountState;
+use uuid::Uuid;
 
 use super::*;
-#[cfg(any(feature = "ipc", feature = "parquet"))]
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use crate::physical_plan::expressions::phys_expr_to_io_expr;
 use crate::prelude::*;
 
@@ -63,6 +72,171 @@ fn prepare_scan_args(
     (projection, predicate)
 }
 
+/// The indices of the columns a file scan reads, in terms of the file schema.
+///
+/// This is what the monitor needs to project the policy that is attached to the file.
+fn scan_projection(
+    with_columns: Option<&Arc<Vec<String>>>,
+    schema: &Schema,
+    has_row_index: bool,
+) -> Vec<usize> {
+    match polars_io::utils::materialize_projection(
+        with_columns.map(|cols| cols.as_slice()),
+        schema,
+        None,
+        has_row_index,
+    ) {
+        Some(projection) => projection,
+        None => (0..schema.len() - has_row_index as usize).collect(),
+    }
+}
+
+/// Register the policy attached to a file scan with the monitor.
+///
+/// `mask` is the predicate that was applied to the rows read from the file, so that only
+/// the policies of the surviving rows are kept. Returns the uuid of the registered dataframe.
+pub(super) fn register_scan_policy(
+    ctx_id: Uuid,
+    policy: &Path,
+    projection: &[usize],
+    mask: Option<&BooleanChunked>,
+) -> PolarsResult<Uuid> {
+    let filter = mask.map(|mask| {
+        mask.iter()
+            .map(|e| e.unwrap_or_default())
+            .collect::<Vec<_>>()
+    });
+    let policy = policy.to_str().ok_or_else(
+        || polars_err!(InvalidOperation: "policy path '{}' is not valid utf-8", policy.display()),
+    )?;
+
+    register_policy_dataframe_parquet(ctx_id, policy, projection, filter.as_deref())
+        .map_err(PolarsError::from)
+}
+
+/// Cut a file scan that was read in full for the monitor down to `n_rows` and apply its
+/// predicate with `filter`, which returns the predicate mask.
+///
+/// The policy of a file covers all of its rows, so the returned mask does too: the rows beyond
+/// `n_rows` are masked out. `None` is returned if every row of the file is kept.
+pub(crate) fn limit_scan_rows<F>(
+    df: &mut DataFrame,
+    n_rows: Option<usize>,
+    filter: F,
+) -> PolarsResult<Option<BooleanChunked>>
+where
+    F: FnOnce(&mut DataFrame) -> PolarsResult<Option<BooleanChunked>>,
+{
+    let height = df.height();
+    let limit = n_rows.map_or(height, |n_rows| n_rows.min(height));
+    if limit < height {
+        *df = df.head(Some(limit));
+    }
+    let mask = filter(df)?;
+    if limit == height {
+        return Ok(mask);
+    }
+
+    let mut mask = mask.unwrap_or_else(|| BooleanChunked::full("", true, limit));
+    mask.append(&BooleanChunked::full("", false, height - limit));
+    Ok(Some(mask))
+}
+
+/// Register the policies of the files a scan read, with `file_heights` rows each, and combine
+/// them in the order of the files. Returns the uuid of the combined dataframe.
+///
+/// A policy resolved per file covers the rows of the consecutive files that resolve to it, each
+/// of these policies is registered with `register` and the part of `mask` that covers its rows.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+pub(crate) fn register_file_policies<F>(
+    ctx_id: Uuid,
+    policy: &ScanPolicy,
+    paths: &[PathBuf],
+    file_heights: &[usize],
+    mask: Option<&BooleanChunked>,
+    register: F,
+) -> PolarsResult<Uuid>
+where
+    F: Fn(&Path, Option<&BooleanChunked>) -> PolarsResult<Uuid>,
+{
+    polars_ensure!(!paths.is_empty(), ComputeError: "no files to read the policies of");
+    if !policy.is_per_file() {
+        return register(&policy.resolve(&paths[0])?, mask);
+    }
+
+    // Group the files that share a policy, a policy covers the rows of all of them.
+    let mut groups: Vec<(Cow<Path>, usize, usize)> = vec![];
+    let mut offset = 0;
+    for (path, &height) in paths.iter().zip(file_heights) {
+        let policy = policy.resolve(path)?;
+        match groups.last_mut() {
+            Some((last, _, len)) if *last == policy => *len += height,
+            _ => groups.push((policy, offset, height)),
+        }
+        offset += height;
+    }
+
+    let df_uuids = groups
+        .iter()
+        .map(|(policy, offset, len)| {
+            let mask = mask.map(|mask| mask.slice(*offset as i64, *len));
+            register(policy, mask.as_ref())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    union_policies(ctx_id, &df_uuids)
+}
+
+/// Combine the policies registered for consecutive parts of a scan, in order. Returns the uuid
+/// of the combined dataframe.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+fn union_policies(ctx_id: Uuid, df_uuids: &[Uuid]) -> PolarsResult<Uuid> {
+    df_uuids[1..].iter().try_fold(df_uuids[0], |lhs, &rhs| {
+        let arg = PlanArgument {
+            argument: Some(plan_argument::Argument::Transform(TransformArgument {})),
+            transform_info: Some(TransformInfo::from_union(lhs, rhs)?),
+        };
+        execute_epilogue(ctx_id, lhs, Some(arg)).map_err(PolarsError::from)
+    })
+}
+
+/// Map the columns requested by a file scan to their position in the scanned [`DataFrame`].
+fn scan_project_list(
+    with_columns: Option<&Arc<Vec<String>>>,
+    df: &DataFrame,
+) -> PolarsResult<Option<ProjectList>> {
+    with_columns
+        .map(|columns| {
+            let project_list = columns
+                .iter()
+                .map(|s| {
+                    df.get_column_index(s)
+                        .ok_or(PolarsError::ComputeError(
+                            format!("Column {} not found", s).into(),
+                        ))
+                        .map(|e| e as u64)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            Ok(ProjectList { project_list })
+        })
+        .transpose()
+}
+
+/// The `GetData` argument a file scan reports to the monitor once the dataframe of its
+/// policy is registered.
+fn scan_plan_argument(state: &ExecutionState, project_list: Option<ProjectList>) -> PlanArgument {
+    PlanArgument {
+        argument: Some(plan_argument::Argument::GetData(GetDataArgument {
+            data_source: Some(DataSource::InMemory(GetDataInMemory {
+                df_uuid: state.active_df_uuid.to_bytes_le().to_vec(),
+                pred: None,
+                project_list,
+            })),
+        })),
+        transform_info: state.transform.clone(),
+    }
+}
+
 /// Producer of an in memory DataFrame
 pub struct DataFrameExec {
     pub(crate) df: Arc<DataFrame>,
@@ -89,7 +263,10 @@ impl DataFrameExec {
 
 impl Executor for DataFrameExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
-        let uuid = self.df.get_uuid();
+        let uuid = match self.df.get_policy() {
+            Some(policy) if state.policy_check => policy.register(state.ctx_id)?,
+            _ => self.df.get_uuid(),
+        };
         state.set_active_df_uuid(uuid);
 
         let df = mem::take(&mut self.df);
@@ -125,9 +302,7 @@ impl Executor for DataFrameExec {
                     b.ok_or(polars_err!(ComputeError: "filter predicate was not of type boolean"))
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
-            state.transform = Some(TransformInfo::from_filter(&pred_bool).map_err(
-                |e| polars_err!(ComputeError: format!("Could not create transform info: {}", e)),
-            )?);
+            state.transform = Some(TransformInfo::from_filter(&pred_bool)?);
             df = df.filter(mask)?;
         }
 
@@ -172,7 +347,9 @@ impl Executor for DataFrameExec {
             self.execute_epilogue(state, Some(plan_arg))?;
         }
 
-        println!("after scan: uuid = {}", state.active_df_uuid);
+        if state.verbose() {
+            eprintln!("after scan: uuid = {}", state.active_df_uuid);
+        }
         Ok(df)
     }
 }
@@ -184,12 +361,45 @@ pub(crate) struct AnonymousScanExec {
     pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
     pub(crate) output_schema: Option<SchemaRef>,
     pub(crate) predicate_has_windows: bool,
+    pub(crate) with_policy: Option<Arc<ScanPolicy>>,
+}
+
+impl AnonymousScanExec {
+    /// The policy file of the scan. A policy that is resolved per file can only be resolved if
+    /// the scan reads a known file.
+    fn policy_file(&self) -> PolarsResult<Cow<'_, Path>> {
+        let policy = self.with_policy.as_deref().unwrap();
+        #[cfg(feature = "json")]
+        if let Some(reader) = self
+            .function
+            .as_any()
+            .downcast_ref::<crate::scan::ndjson::LazyJsonLineReader>()
+        {
+            return policy.resolve(&reader.path);
+        }
+        match policy {
+            ScanPolicy::File(path) => Ok(Cow::Borrowed(path)),
+            _ => polars_bail!(
+                InvalidOperation: "the policy '{}' is resolved per file, but the scan does not read a file", policy
+            ),
+        }
+    }
 }
 
 impl Executor for AnonymousScanExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        polars_ensure!(!(state.policy_check && self.with_policy.is_none()),
+            InvalidOperation: "Policy check requested but no policy was provided");
+
+        // The policy covers every row of the file, so under policy checking the file is read in
+        // full and cut down to `n_rows` afterwards.
+        let (scan_n_rows, n_rows) = if state.policy_check {
+            (None, self.file_options.n_rows)
+        } else {
+            (self.file_options.n_rows, None)
+        };
         let mut args = AnonymousScanArgs {
-            n_rows: self.file_options.n_rows,
+            n_rows: scan_n_rows,
             with_columns: self.file_options.with_columns.clone(),
             schema: self.file_info.schema.clone(),
             output_schema: self.output_schema.clone(),
@@ -199,31 +409,66 @@ impl Executor for AnonymousScanExec {
             state.insert_has_window_function_flag()
         }
 
-        match (self.function.allows_predicate_pushdown(), &self.predicate) {
+        // The monitor needs the predicate mask, so we cannot let the scan apply the predicate.
+        let allows_predicate_pushdown =
+            self.function.allows_predicate_pushdown() && !state.policy_check;
+
+        let (df, mask) = match (allows_predicate_pushdown, &self.predicate) {
             (true, Some(predicate)) => state.record(
                 || {
                     args.predicate = predicate.as_expression().cloned();
-                    self.function.scan(args)
+                    self.function.scan(args).map(|df| (df, None))
                 },
                 "anonymous_scan".into(),
             ),
             (false, Some(predicate)) => state.record(
                 || {
                     let mut df = self.function.scan(args)?;
-                    let s = predicate.evaluate(&df, state)?;
-                    if self.predicate_has_windows {
-                        state.clear_window_expr_cache()
-                    }
-                    let mask = s.bool().map_err(
-                        |_| polars_err!(ComputeError: "filter predicate was not of type boolean"),
-                    )?;
-                    df = df.filter(mask)?;
-
-                    Ok(df)
+                    let mask = limit_scan_rows(&mut df, n_rows, |df| {
+                        let s = predicate.evaluate(df, state)?;
+                        if self.predicate_has_windows {
+                            state.clear_window_expr_cache()
+                        }
+                        let mask = s.bool().map_err(
+                            |_| polars_err!(ComputeError: "filter predicate was not of type boolean"),
+                        )?;
+                        *df = df.filter(mask)?;
+                        Ok(Some(mask.clone()))
+                    })?;
+
+                    Ok((df, mask))
+                },
+                "anonymous_scan".into(),
+            ),
+            _ => state.record(
+                || {
+                    let mut df = self.function.scan(args)?;
+                    let mask = limit_scan_rows(&mut df, n_rows, |_| Ok(None))?;
+                    Ok((df, mask))
                 },
                 "anonymous_scan".into(),
             ),
-            _ => state.record(|| self.function.scan(args), "anonymous_scan".into()),
+        }?;
+
+        if state.policy_check {
+            let projection = scan_projection(
+                self.file_options.with_columns.as_ref(),
+                &self.file_info.schema,
+                false,
+            );
+            let df_uuid = register_scan_policy(
+                state.ctx_id,
+                &self.policy_file()?,
+                &projection,
+                mask.as_ref(),
+            )?;
+            state.set_active_df_uuid(df_uuid);
+
+            let project_list = scan_project_list(self.file_options.with_columns.as_ref(), &df)?;
+            let plan_arg = scan_plan_argument(state, project_list);
+            self.execute_epilogue(state, Some(plan_arg))?;
         }
+
+        Ok(df)
     }
 }
@@ -1,6 +1,7 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use picachv::native::{register_policy_dataframe_bin, register_policy_dataframe_parquet};
+#[cfg(feature = "cloud")]
+use picachv::native::register_policy_dataframe_bin;
 use picachv::transform_info::Information;
 use picachv::FilterInformation;
 use polars_core::config;
@@ -23,27 +24,109 @@ pub struct ParquetExec {
     file_options: FileScanOptions,
     #[allow(dead_code)]
     metadata: Option<Arc<FileMetaData>>,
-    with_policy: Option<Arc<PathBuf>>,
+    with_policy: Option<Arc<ScanPolicy>>,
     active_df_uuid: Option<Uuid>,
 }
 
 impl ParquetExec {
-    pub(crate) fn load_policy(&mut self, df: &DataFrame, mask: Option<ChunkedArray<BooleanType>>, ctx_id: Uuid) -> PolarsResult<()> {
-        // HACK: This is to reliably exclude the time taken to register the policy.
-        if let Some(policy) = self.with_policy.as_ref() {
-            let filter = mask.as_ref().map(|mask| mask.iter().map(|e| e.unwrap_or_default()).collect::<Vec<_>>());
-            let projection = match materialize_projection(self.file_options.with_columns.as_ref().map(|e| e.as_slice()), &self.file_info.schema, None, false) {
-                Some(proj) => proj,
-                None => df.fields().iter().enumerate().map(|(i, _)| i as usize).collect::<Vec<_>>(),
-            };
+    /// Register the policy of the scanned files with the monitor. `df` and `mask` come from the
+    /// read of the first execution, so registering the policy costs no extra read.
+    ///
+    /// A policy resolved per file covers the rows read from the consecutive files (with heights
+    /// `file_heights`) that resolve to it. Each of these policies is registered on its own and the
+    /// registered dataframes are combined in the order of the files.
+    fn load_policy(
+        &mut self,
+        df: &DataFrame,
+        mask: Option<BooleanChunked>,
+        file_heights: &[usize],
+        ctx_id: Uuid,
+    ) -> PolarsResult<()> {
+        let Some(policy) = self.with_policy.as_ref() else {
+            return Ok(());
+        };
+        let projection = match materialize_projection(
+            self.file_options
+                .with_columns
+                .as_ref()
+                .map(|e| e.as_slice()),
+            &self.file_info.schema,
+            None,
+            false,
+        ) {
+            Some(proj) => proj,
+            None => (0..df.width()).collect::<Vec<_>>(),
+        };
 
-            self.active_df_uuid.replace(
-            register_policy_dataframe_parquet(ctx_id, self.with_policy.as_ref().unwrap().as_path().to_str().unwrap(), &projection, filter.as_ref().map(|f| f.as_slice())).map_err(|e| PolarsError::InvalidOperation(e.to_string().into()))?);
-        }
+        let df_uuid = register_file_policies(
+            ctx_id,
+            policy,
+            &self.paths,
+            file_heights,
+            mask.as_ref(),
+            |policy, mask| self.register_policy(ctx_id, policy, &projection, mask),
+        )?;
+        self.active_df_uuid.replace(df_uuid);
 
         Ok(())
     }
 
+    /// Register a single policy file with the monitor. The monitor only reads local files, so a
+    /// policy in cloud storage is fetched with the cloud options of the scan and registered from
+    /// its serialized bytes.
+    fn register_policy(
+        &self,
+        ctx_id: Uuid,
+        policy: &Path,
+        projection: &[usize],
+        mask: Option<&BooleanChunked>,
+    ) -> PolarsResult<Uuid> {
+        if !is_cloud_url(policy) {
+            return register_scan_policy(ctx_id, policy, projection, mask);
+        }
+
+        #[cfg(not(feature = "cloud"))]
+        {
+            polars_bail!(
+                ComputeError: "policy '{}' is in cloud storage; activate the 'cloud' feature",
+                policy.display()
+            )
+        }
+
+        #[cfg(feature = "cloud")]
+        {
+            let uri = policy.to_string_lossy();
+            let bytes = polars_io::pl_async::get_runtime().block_on_potential_spawn(
+                polars_io::cloud::get_object(&uri, self.cloud_options.as_ref()),
+            )?;
+            let df_uuid = register_policy_dataframe_bin(ctx_id, &bytes)?;
+
+            // The serialized policy covers every column and row of the file, so it is projected
+            // and filtered here like the monitor does when it reads a local policy file.
+            let filter = mask.map(|mask| {
+                mask.iter()
+                    .map(|e| e.unwrap_or_default())
+                    .collect::<Vec<_>>()
+            });
+            let arg = PlanArgument {
+                argument: Some(plan_argument::Argument::GetData(GetDataArgument {
+                    data_source: Some(DataSource::InMemory(GetDataInMemory {
+                        df_uuid: df_uuid.to_bytes_le().to_vec(),
+                        pred: None,
+                        project_list: Some(ProjectList {
+                            project_list: projection.iter().map(|&i| i as u64).collect(),
+                        }),
+                    })),
+                })),
+                transform_info: filter
+                    .as_deref()
+                    .map(TransformInfo::from_filter)
+                    .transpose()?,
+            };
+            execute_epilogue(ctx_id, df_uuid, Some(arg)).map_err(PolarsError::from)
+        }
+    }
+
     pub(crate) fn new(
         paths: Arc<[PathBuf]>,
         file_info: FileInfo,
@@ -52,10 +135,9 @@ impl ParquetExec {
         cloud_options: Option<CloudOptions>,
         file_options: FileScanOptions,
         metadata: Option<Arc<FileMetaData>>,
-        with_policy: Option<Arc<PathBuf>>,
-        ctx_id: Uuid,
-    ) -> PolarsResult<Self> {
-        let mut executor = ParquetExec {
+        with_policy: Option<Arc<ScanPolicy>>,
+    ) -> Self {
+        ParquetExec {
             paths,
             file_info,
             predicate,
@@ -65,17 +147,10 @@ impl ParquetExec {
             metadata,
             with_policy,
             active_df_uuid: None,
-        };
-
-        if let Some(policy) = executor.with_policy.as_ref() {
-            let (df, mask) = executor.read()?;
-            executor.load_policy(&df, mask, ctx_id)?;
         }
-
-        Ok(executor)
     }
 
-    fn read_par(&mut self) -> PolarsResult<Vec<DataFrame>> {
+    fn read_par(&mut self, n_rows: Option<usize>) -> PolarsResult<Vec<DataFrame>> {
         let parallel = match self.options.parallel {
             ParallelStrategy::Auto if self.paths.len() > POOL.current_num_threads() => {
                 ParallelStrategy::RowGroups
@@ -85,7 +160,7 @@ impl ParquetExec {
 
         let mut result = vec![];
 
-        let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
+        let mut remaining_rows_to_read = n_rows.unwrap_or(usize::MAX);
         let mut base_row_index = self.file_options.row_index.take();
 
         // Limit no. of files at a time to prevent open file limits.
@@ -187,7 +262,7 @@ impl ParquetExec {
     }
 
     #[cfg(feature = "cloud")]
-    async fn read_async(&mut self) -> PolarsResult<Vec<DataFrame>> {
+    async fn read_async(&mut self, n_rows: Option<usize>) -> PolarsResult<Vec<DataFrame>> {
         let verbose = verbose();
         let first_schema = self
             .file_info
@@ -209,7 +284,7 @@ impl ParquetExec {
             eprintln!("POLARS PREFETCH_SIZE: {}", batch_size)
         }
 
-        let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
+        let mut remaining_rows_to_read = n_rows.unwrap_or(usize::MAX);
         let mut base_row_index = self.file_options.row_index.take();
         let mut processed = 0;
         for (batch_idx, paths) in self.paths.chunks(batch_size).enumerate() {
@@ -272,6 +347,7 @@ impl ParquetExec {
             let file_options = &self.file_options;
             let use_statistics = self.options.use_statistics;
             let predicate = &self.predicate;
+            let with_policy = &self.with_policy;
             let base_row_index_ref = &base_row_index;
 
             if verbose {
@@ -320,7 +396,9 @@ impl ParquetExec {
                             .with_row_index(row_index)
                             .with_projection(projection)
                             .use_statistics(use_statistics)
-                            .with_predicate(predicate)
+                            // The monitor must know which rows the predicate removes, so with a
+                            // policy it is applied after the read.
+                            .with_predicate(predicate.filter(|_| with_policy.is_none()))
                             .set_rechunk(false)
                             .with_hive_partition_columns(hive_partitions)
                             .finish()
@@ -344,7 +422,23 @@ impl ParquetExec {
         Ok(result)
     }
 
-    fn read(&mut self) -> PolarsResult<(DataFrame, Option<BooleanChunked>)> {
+    /// Read the files. Next to the predicate mask, the number of rows read from every file is
+    /// returned, in the order of the files.
+    ///
+    /// If `policy_check` is set, the files are read in full and `n_rows` is applied after
+    /// reading, as the monitor needs to know which rows of the files survived. The returned mask
+    /// then covers every row of the files.
+    #[allow(clippy::type_complexity)]
+    fn read(
+        &mut self,
+        policy_check: bool,
+    ) -> PolarsResult<(DataFrame, Option<BooleanChunked>, Vec<usize>)> {
+        let (n_rows, post_n_rows) = if policy_check {
+            (None, self.file_options.n_rows)
+        } else {
+            (self.file_options.n_rows, None)
+        };
+
         // FIXME: The row index implementation is incorrect when a predicate is
         // applied. This code mitigates that by applying the predicate after the
         // collection of the entire dataframe if a row index is requested. This is
@@ -372,12 +466,16 @@ impl ParquetExec {
                     self.file_options.row_index.is_some(),
                     hive_partitions.as_deref(),
                 );
-                return Ok((materialize_empty_df(
-                    projection.as_deref(),
-                    self.file_info.reader_schema.as_ref().unwrap(),
-                    hive_partitions.as_deref(),
-                    self.file_options.row_index.as_ref(),
-                ), None));
+                return Ok((
+                    materialize_empty_df(
+                        projection.as_deref(),
+                        self.file_info.reader_schema.as_ref().unwrap(),
+                        hive_partitions.as_deref(),
+                        self.file_options.row_index.as_ref(),
+                    ),
+                    None,
+                    vec![],
+                ));
             },
         };
         let force_async = config::force_async();
@@ -394,23 +492,23 @@ impl ParquetExec {
                     eprintln!("ASYNC READING FORCED");
                 }
 
-                polars_io::pl_async::get_runtime().block_on_potential_spawn(self.read_async())?
+                polars_io::pl_async::get_runtime()
+                    .block_on_potential_spawn(self.read_async(n_rows))?
             }
         } else {
-            self.read_par()?
+            self.read_par(n_rows)?
         };
 
+        let file_heights = out.iter().map(|df| df.height()).collect();
         let mut out = accumulate_dataframes_vertical(out)?;
-        let mask = match post_predicate.as_ref() {
-            Some(mask) => Some(mask.evaluate_io(&out)?.bool()?.clone()),
-            None => None,
-        };
-        polars_io::predicates::apply_predicate(&mut out, post_predicate.as_deref(), true)?;
+        let mask = limit_scan_rows(&mut out, post_n_rows, |df| {
+            polars_io::predicates::apply_predicate(df, post_predicate.as_deref(), true)
+        })?;
 
         if self.file_options.rechunk {
             out.as_single_chunk_par();
         }
-        Ok((out, mask))
+        Ok((out, mask, file_heights))
     }
 }
 
@@ -430,44 +528,18 @@ impl Executor for ParquetExec {
             Cow::Borrowed("")
         };
 
-        let (df, mask) = state.record(|| self.read(), profile_name)?;
+        let (df, mask, file_heights) =
+            state.record(|| self.read(state.policy_check), profile_name)?;
 
         if state.policy_check {
             if self.active_df_uuid.is_none() {
-                self.load_policy(&df, mask, state.ctx_id)?;
+                self.load_policy(&df, mask, &file_heights, state.ctx_id)?;
             }
 
             state.active_df_uuid = self.active_df_uuid.clone().unwrap();
 
-            let project_list = match self.file_options.with_columns.as_ref() {
-                    Some(project_list) => {
-                        let project_list = project_list
-                            .iter()
-                            .map(|s| {
-                                df.get_column_index(s)
-                                    .ok_or(PolarsError::ComputeError(
-                                        format!("Column {} not found", s).into(),
-                                    ))
-                                    .map(|e| e as u64)
-                            })
-                            .collect::<PolarsResult<Vec<_>>>()?;
-    
-                        Some(ProjectList { project_list })
-                    },
-                    None => None,
-                };
-
-            // Then construct the plan argument and execute the epilogue.
-            let plan_arg = PlanArgument {
-                argument: Some(plan_argument::Argument::GetData(GetDataArgument {
-                    data_source: Some(DataSource::InMemory(GetDataInMemory {
-                        df_uuid: state.active_df_uuid.clone().to_bytes_le().to_vec(),
-                        pred: None,
-                        project_list,
-                    })),
-                })),
-                transform_info: state.transform.clone(),
-            };
+            let project_list = scan_project_list(self.file_options.with_columns.as_ref(), &df)?;
+            let plan_arg = scan_plan_argument(state, project_list);
 
             self.execute_epilogue(state, Some(plan_arg))?;
         }
@@ -475,4 +547,3 @@ impl Executor for ParquetExec {
         Ok(df)
     }
 }
-
@@ -1,3 +1,5 @@
+use polars_core::utils::slice_offsets;
+
 use super::*;
 
 pub struct SliceExec {
@@ -15,10 +17,17 @@ impl Executor for SliceExec {
             }
         }
         let df = self.input.execute(state)?;
+        let height = df.height();
+
+        let df = state.record(|| df.slice(self.offset, self.len as usize), "slice".into());
+
+        if state.policy_check {
+            let (offset, len) = slice_offsets(self.offset, self.len as usize, height);
+            let mut mask = vec![false; height];
+            mask[offset..offset + len].fill(true);
+            self.execute_epilogue(state, Some(filter_plan_argument(&mask)?))?;
+        }
 
-        state.record(
-            || Ok(df.slice(self.offset, self.len as usize)),
-            "slice".into(),
-        )
+        Ok(df)
     }
 }
@@ -16,7 +16,7 @@ impl SortExec {
         &mut self,
         state: &mut ExecutionState,
         mut df: DataFrame,
-    ) -> PolarsResult<DataFrame> {
+    ) -> PolarsResult<(DataFrame, Option<PlanArgument>)> {
         state.should_stop()?;
         df.as_single_chunk_par();
 
@@ -43,19 +43,15 @@ impl SortExec {
             .map(|idx| idx.unwrap_or_default() as _)
             .collect();
 
-        if state.policy_check {
-            let plan_arg = PlanArgument {
-                // Sort does nothing but sort the data.
-                argument: Some(Argument::Transform(TransformArgument {})),
-                transform_info: Some(TransformInfo {
-                    information: Some(Information::Reorder(ReorderInformation { perm })),
-                }),
-            };
+        let plan_arg = state.policy_check.then(|| PlanArgument {
+            // Sort does nothing but sort the data.
+            argument: Some(Argument::Transform(TransformArgument {})),
+            transform_info: Some(TransformInfo {
+                information: Some(Information::Reorder(ReorderInformation { perm })),
+            }),
+        });
 
-            self.execute_epilogue(state, Some(plan_arg))?;
-        }
-
-        Ok(df)
+        Ok((df, plan_arg))
     }
 }
 
@@ -81,11 +77,17 @@ impl Executor for SortExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        let (df, plan_arg) = if state.has_node_timer() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
+        }?;
+
+        if let Some(plan_arg) = plan_arg {
+            self.execute_epilogue(state, Some(plan_arg))?;
         }
+
+        Ok(df)
     }
 }
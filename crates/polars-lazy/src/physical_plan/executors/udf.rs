@@ -14,13 +14,43 @@ impl Executor for UdfExec {
                 eprintln!("run UdfExec")
             }
         }
-        let df = self.input.execute(state)?;
+        let is_pipeline = matches!(self.function, FunctionNode::Pipeline { .. });
+        // The input of a streaming pipeline is an empty dummy scan, it must not be reported to
+        // the monitor.
+        let df = if state.policy_check && is_pipeline {
+            DataFrame::empty()
+        } else {
+            self.input.execute(state)?
+        };
+
+        // The rows kept by `drop_nulls`, reported to the monitor as a filter. Other functions are
+        // either refused by the planner or do not change the rows.
+        let drop_nulls_mask = match &self.function {
+            FunctionNode::DropNulls { subset } if state.policy_check => {
+                let mut mask = BooleanChunked::full("", true, df.height());
+                for name in subset.iter() {
+                    mask = mask & df.column(name)?.is_not_null();
+                }
+                Some(mask.into_no_null_iter().collect::<Vec<_>>())
+            },
+            _ => None,
+        };
 
         let profile_name = if state.has_node_timer() {
             Cow::Owned(format!("{}", self.function))
         } else {
             Cow::Borrowed("")
         };
-        state.record(|| self.function.evaluate(df), profile_name)
+        let df = state.record(|| self.function.evaluate(df), profile_name)?;
+
+        // A streaming pipeline reports its operations to the monitor itself and tags the
+        // output with the uuid of the resulting dataframe.
+        if state.policy_check && is_pipeline {
+            state.set_active_df_uuid(df.get_uuid());
+        }
+        if let Some(mask) = drop_nulls_mask {
+            self.execute_epilogue(state, Some(filter_plan_argument(&mask)?))?;
+        }
+        Ok(df)
     }
 }
@@ -129,10 +129,10 @@ impl Executor for UnionExec {
         })?;
 
         if state.policy_check {
-            state.transform.replace(
-                TransformInfo::from_union(active_df_uuids[0], active_df_uuids[1])
-                    .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?,
-            );
+            state.transform.replace(TransformInfo::from_union(
+                active_df_uuids[0],
+                active_df_uuids[1],
+            )?);
 
             let arg = PlanArgument {
                 argument: Some(Argument::Transform(TransformArgument {})),
@@ -1,3 +1,5 @@
+use polars_core::utils::slice_offsets;
+
 use super::*;
 
 pub(crate) struct UniqueExec {
@@ -5,6 +7,55 @@ pub(crate) struct UniqueExec {
     pub(crate) options: DistinctOptions,
 }
 
+impl UniqueExec {
+    /// Keep the unique rows in their original order and return the mask of the kept rows, so
+    /// the result can be reported to the monitor as a filter over the input.
+    fn unique_with_mask(&self, df: &DataFrame) -> PolarsResult<(DataFrame, Vec<bool>)> {
+        let names = match self.options.subset.as_ref() {
+            Some(subset) => subset.iter().map(|s| s.as_str()).collect(),
+            None => df.get_column_names(),
+        };
+
+        let mut idx = match self.options.keep_strategy {
+            UniqueKeepStrategy::None => {
+                let mask = df.select(names)?.is_unique()?;
+                mask.into_no_null_iter()
+                    .enumerate()
+                    .filter_map(|(i, keep)| keep.then_some(i))
+                    .collect::<Vec<_>>()
+            },
+            keep => {
+                let gb = df.group_by(names)?;
+                gb.get_groups()
+                    .iter()
+                    .map(|g| match (keep, g) {
+                        (UniqueKeepStrategy::Last, GroupsIndicator::Idx((_, idx))) => {
+                            idx[idx.len() - 1] as usize
+                        },
+                        (UniqueKeepStrategy::Last, GroupsIndicator::Slice([first, len])) => {
+                            (first + len - 1) as usize
+                        },
+                        (_, g) => g.first() as usize,
+                    })
+                    .collect::<Vec<_>>()
+            },
+        };
+        idx.sort_unstable();
+
+        let (offset, len) = match self.options.slice {
+            Some((offset, len)) => slice_offsets(offset, len, idx.len()),
+            None => (0, idx.len()),
+        };
+        let mut mask = vec![false; df.height()];
+        for &i in &idx[offset..offset + len] {
+            mask[i] = true;
+        }
+
+        let df = df.filter(&BooleanChunked::from_slice("", &mask))?;
+        Ok((df, mask))
+    }
+}
+
 impl Executor for UniqueExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         state.should_stop()?;
@@ -15,6 +66,14 @@ impl Executor for UniqueExec {
             }
         }
         let df = self.input.execute(state)?;
+
+        if state.policy_check {
+            let (df, mask) =
+                state.record(|| self.unique_with_mask(&df), Cow::Borrowed("unique()"))?;
+            self.execute_epilogue(state, Some(filter_plan_argument(&mask)?))?;
+            return Ok(df);
+        }
+
         let subset = self.options.subset.as_ref().map(|v| &***v);
         let keep = self.options.keep_strategy;
 
@@ -5,7 +5,7 @@ use arrow::compute::concatenate::concatenate;
 use arrow::legacy::utils::CustomIterTools;
 use arrow::offset::Offsets;
 use picachv::expr_argument::Argument;
-use picachv::native::{build_expr, reify_expression};
+use picachv::native::reify_expression;
 use picachv::{AggExpr, ExprArgument};
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
@@ -34,14 +34,9 @@ impl AggregationExpr {
         ctx_id: Uuid,
         policy_check: bool,
     ) -> PolarsResult<Self> {
-        let expr_uuid = if policy_check {
-            // Wrap into another function.
-            let arg = arg_to_expr(&expr, agg_type)?;
-
-            build_expr(ctx_id, arg).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
-        } else {
-            Default::default()
-        };
+        let expr_uuid = build_policy_expr(ctx_id, policy_check, || {
+            agg_argument(expr.get_uuid(), agg_type)
+        })?;
 
         Ok(Self {
             input: expr,
@@ -52,27 +47,35 @@ impl AggregationExpr {
     }
 }
 
-fn arg_to_expr(
-    expr: &Arc<dyn PhysicalExpr>,
-    agg_type: GroupByMethod,
-) -> PolarsResult<ExprArgument> {
-    let input_uuid = expr.get_uuid().to_bytes_le().to_vec();
-    let arg = ExprArgument {
-        argument: Some(Argument::Agg(AggExpr {
-            input_uuid,
-            method: match agg_type {
-                GroupByMethod::Sum => picachv::GroupByMethod::Sum,
-                GroupByMethod::Mean => picachv::GroupByMethod::Mean,
-                GroupByMethod::Min => picachv::GroupByMethod::Min,
-                GroupByMethod::Max => picachv::GroupByMethod::Max,
-                GroupByMethod::Count { .. } => picachv::GroupByMethod::Len,
-                GroupByMethod::NUnique => picachv::GroupByMethod::Min,
-                _ => polars_bail!(ComputeError: "Aggregation method not supported: {:?}", agg_type),
-            } as _,
-        })),
+/// The monitor encoding of aggregating `input` with `agg_type`. The parameters of a method, such
+/// as the quantile or `ddof`, are not part of the encoding.
+pub(crate) fn agg_argument(input: Uuid, agg_type: GroupByMethod) -> ExprArgument {
+    let method = match agg_type {
+        GroupByMethod::Min | GroupByMethod::NanMin => picachv::GroupByMethod::Min,
+        GroupByMethod::Max | GroupByMethod::NanMax => picachv::GroupByMethod::Max,
+        GroupByMethod::Median => picachv::GroupByMethod::Median,
+        GroupByMethod::Mean => picachv::GroupByMethod::Mean,
+        GroupByMethod::First => picachv::GroupByMethod::First,
+        GroupByMethod::Last => picachv::GroupByMethod::Last,
+        GroupByMethod::Sum => picachv::GroupByMethod::Sum,
+        GroupByMethod::Groups => picachv::GroupByMethod::Groups,
+        GroupByMethod::NUnique => picachv::GroupByMethod::NUnique,
+        GroupByMethod::Quantile(_, _) => picachv::GroupByMethod::Quantile,
+        GroupByMethod::Count { .. } => picachv::GroupByMethod::Len,
+        GroupByMethod::Implode => picachv::GroupByMethod::Implode,
+        GroupByMethod::Std(_) => picachv::GroupByMethod::Std,
+        GroupByMethod::Var(_) => picachv::GroupByMethod::Var,
     };
+    method_argument(input, method)
+}
 
-    Ok(arg)
+fn method_argument(input: Uuid, method: picachv::GroupByMethod) -> ExprArgument {
+    ExprArgument {
+        argument: Some(Argument::Agg(AggExpr {
+            input_uuid: input.to_bytes_le().to_vec(),
+            method: method as _,
+        })),
+    }
 }
 
 impl PhysicalExpr for AggregationExpr {
@@ -137,8 +140,10 @@ impl PhysicalExpr for AggregationExpr {
 
                     if state.policy_check {
                         let bytes = inputs_as_arrow(&[&agg_s])?;
-                        reify_expression(state.ctx_id, self.expr_uuid, &bytes)
-                            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+                        reify_expression(state.ctx_id, self.expr_uuid, &bytes).map_err(|err| {
+                            PolarsError::from(err)
+                                .with_policy_context(&self.agg_type.to_string(), Some(&keep_name))
+                        })?;
                     }
 
                     AggregatedScalar(rename_series(agg_s, &keep_name))
@@ -149,8 +154,10 @@ impl PhysicalExpr for AggregationExpr {
 
                     if state.policy_check {
                         let bytes = inputs_as_arrow(&[&agg_s])?;
-                        reify_expression(state.ctx_id, self.expr_uuid, &bytes)
-                            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+                        reify_expression(state.ctx_id, self.expr_uuid, &bytes).map_err(|err| {
+                            PolarsError::from(err)
+                                .with_policy_context(&self.agg_type.to_string(), Some(&keep_name))
+                        })?;
                     }
 
                     AggregatedScalar(rename_series(agg_s, &keep_name))
@@ -606,6 +613,7 @@ pub struct AggQuantileExpr {
     pub(crate) input: Arc<dyn PhysicalExpr>,
     pub(crate) quantile: Arc<dyn PhysicalExpr>,
     pub(crate) interpol: QuantileInterpolOptions,
+    expr_uuid: Uuid,
 }
 
 impl AggQuantileExpr {
@@ -613,12 +621,19 @@ impl AggQuantileExpr {
         input: Arc<dyn PhysicalExpr>,
         quantile: Arc<dyn PhysicalExpr>,
         interpol: QuantileInterpolOptions,
-    ) -> Self {
-        Self {
+        ctx_id: Uuid,
+        policy_check: bool,
+    ) -> PolarsResult<Self> {
+        let expr_uuid = build_policy_expr(ctx_id, policy_check, || {
+            method_argument(input.get_uuid(), picachv::GroupByMethod::Quantile)
+        })?;
+
+        Ok(Self {
             input,
             quantile,
             interpol,
-        }
+            expr_uuid,
+        })
     }
 
     fn get_quantile(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<f64> {
@@ -640,6 +655,10 @@ impl PhysicalExpr for AggQuantileExpr {
         "AggQuantile"
     }
 
+    fn get_uuid(&self) -> Uuid {
+        self.expr_uuid
+    }
+
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
         let input = self.input.evaluate(df, state)?;
         let quantile = self.get_quantile(df, state)?;
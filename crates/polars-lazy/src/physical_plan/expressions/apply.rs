@@ -53,8 +53,7 @@ impl ApplyExpr {
                     let input_uuids = inputs.iter().map(|e| e.get_uuid()).collect::<Vec<_>>();
                     let arg = f.to_expr_argument(&input_uuids)?;
 
-                    build_expr(ctx_id, arg)
-                        .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
+                    build_expr(ctx_id, arg)?
                 } else {
                     Uuid::nil()
                 }
@@ -103,6 +102,12 @@ impl ApplyExpr {
         }
     }
 
+    /// Set the uuid under which the monitor knows the expression.
+    pub(crate) fn with_expr_id(mut self, expr_id: Uuid) -> Self {
+        self.expr_id = expr_id;
+        self
+    }
+
     #[allow(clippy::ptr_arg)]
     fn prepare_multiple_inputs<'a>(
         &self,
@@ -346,9 +351,8 @@ impl PhysicalExpr for ApplyExpr {
         if state.policy_check {
             let inputs = inputs.iter().map(|e| e).collect::<Vec<_>>();
             let bytes = inputs_as_arrow(&inputs)?;
-            reify_expression(state.ctx_id, self.expr_id, &bytes).map_err(|e| {
-                PolarsError::ComputeError(format!("Error evaluating expression: {}", e).into())
-            })?;
+            reify_expression(state.ctx_id, self.expr_id, &bytes)
+                .map_err(|err| expr_policy_context(err, &self.expr))?;
         }
 
         if self.allow_rename {
@@ -366,11 +370,6 @@ impl PhysicalExpr for ApplyExpr {
         groups: &'a GroupsProxy,
         state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
-        println!(
-            "evaluate_on_groups for applyexpr, inputs len: {}",
-            self.inputs.len()
-        );
-
         polars_ensure!(
             self.allow_group_aware,
             expr = self.expr,
@@ -391,9 +390,8 @@ impl PhysicalExpr for ApplyExpr {
 
             if state.policy_check {
                 let bytes = inputs_as_arrow(&[ac.series()])?;
-                reify_expression(state.ctx_id, self.expr_id, &bytes).map_err(|e| {
-                    PolarsError::ComputeError(format!("Error evaluating expression: {}", e).into())
-                })?;
+                reify_expression(state.ctx_id, self.expr_id, &bytes)
+                    .map_err(|err| expr_policy_context(err, &self.expr))?;
             }
 
             Ok(ac)
@@ -402,9 +400,8 @@ impl PhysicalExpr for ApplyExpr {
             if state.policy_check {
                 let values = acs.iter().map(|ac| ac.series()).collect::<Vec<_>>();
                 let bytes = inputs_as_arrow(&values)?;
-                reify_expression(state.ctx_id, self.expr_id, &bytes).map_err(|e| {
-                    PolarsError::ComputeError(format!("Error evaluating expression: {}", e).into())
-                })?;
+                reify_expression(state.ctx_id, self.expr_id, &bytes)
+                    .map_err(|err| expr_policy_context(err, &self.expr))?;
             }
 
             match self.collect_groups {
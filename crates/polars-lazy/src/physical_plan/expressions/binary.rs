@@ -1,5 +1,5 @@
 use picachv::expr_argument::Argument;
-use picachv::native::{build_expr, reify_expression};
+use picachv::native::reify_expression;
 use picachv::ExprArgument;
 use polars_core::prelude::*;
 use polars_core::POOL;
@@ -29,24 +29,9 @@ impl BinaryExpr {
         ctx_id: Uuid,
         policy_check: bool,
     ) -> PolarsResult<Self> {
-        let expr_id = if policy_check {
-            let lhs_uuid = left.get_uuid();
-            let rhs_uuid = right.get_uuid();
-
-            let expr_arg = ExprArgument {
-                argument: Some(Argument::Binary(picachv::BinaryExpr {
-                    left_uuid: lhs_uuid.to_bytes_le().to_vec(),
-                    op: Some(op_to_binop(op)?),
-                    right_uuid: rhs_uuid.to_bytes_le().to_vec(),
-                })),
-            };
-
-            build_expr(ctx_id, expr_arg).map_err(|e| {
-                PolarsError::ComputeError(format!("Could not build expression: {}", e).into())
-            })?
-        } else {
-            Default::default()
-        };
+        let expr_id = build_policy_expr(ctx_id, policy_check, || {
+            binary_argument(left.get_uuid(), op, right.get_uuid())
+        })?;
 
         Ok(Self {
             left,
@@ -59,6 +44,32 @@ impl BinaryExpr {
     }
 }
 
+/// The monitor encoding of `left op right`. The operators that the monitor has no counterpart
+/// for are encoded as an `Apply` over both operands.
+pub(crate) fn binary_argument(left: Uuid, op: Operator, right: Uuid) -> ExprArgument {
+    if let Some(op) = op_to_binop(op) {
+        return ExprArgument {
+            argument: Some(Argument::Binary(picachv::BinaryExpr {
+                left_uuid: left.to_bytes_le().to_vec(),
+                op: Some(op),
+                right_uuid: right.to_bytes_le().to_vec(),
+            })),
+        };
+    }
+
+    let name = match op {
+        // Unlike `==` and `!=`, these compare nulls as values instead of propagating them.
+        Operator::EqValidity => "eq_missing",
+        Operator::NotEqValidity => "ne_missing",
+        Operator::TrueDivide => "truediv",
+        Operator::FloorDivide => "floordiv",
+        Operator::Modulus => "mod",
+        Operator::Xor => "xor",
+        _ => unreachable!("{op:?} has a monitor counterpart"),
+    };
+    apply_argument(name, &[], &[left, right])
+}
+
 /// Can partially do operations in place.
 fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResult<Series> {
     match op {
@@ -246,7 +257,7 @@ impl PhysicalExpr for BinaryExpr {
             let values = inputs_as_arrow(&[&lhs, &rhs])?;
 
             reify_expression(state.ctx_id, self.expr_id, &values)
-                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+                .map_err(|err| expr_policy_context(err, &self.expr))?;
         }
 
         apply_operator_owned(lhs, rhs, self.op)
@@ -287,9 +298,8 @@ impl PhysicalExpr for BinaryExpr {
             };
 
             let bytes = inputs_as_arrow(&[&ac_l, &ac_r])?;
-            reify_expression(state.ctx_id, self.expr_id, &bytes).map_err(|e| {
-                PolarsError::ComputeError(format!("Error evaluating expression: {}", e).into())
-            })?;
+            reify_expression(state.ctx_id, self.expr_id, &bytes)
+                .map_err(|err| expr_policy_context(err, &self.expr))?;
         }
 
         match (ac_l.agg_state(), ac_r.agg_state()) {
@@ -553,9 +563,8 @@ impl PartitionedAggregation for BinaryExpr {
 
         if state.policy_check {
             let values = inputs_as_arrow(&[&left, &right])?;
-            reify_expression(state.ctx_id, self.expr_id, &values).map_err(|e| {
-                PolarsError::ComputeError(format!("Error evaluating expression: {}", e).into())
-            })?;
+            reify_expression(state.ctx_id, self.expr_id, &values)
+                .map_err(|err| expr_policy_context(err, &self.expr))?;
         }
 
         apply_operator(&left, &right, self.op)
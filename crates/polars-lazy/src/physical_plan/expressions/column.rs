@@ -37,8 +37,7 @@ impl ColumnExpr {
                 )),
             };
 
-            build_expr(ctx_id, expr_arg)
-                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
+            build_expr(ctx_id, expr_arg)?
         } else {
             Default::default()
         };
@@ -190,9 +189,8 @@ impl PhysicalExpr for ColumnExpr {
                             Some(out) => {
                                 if state.policy_check {
                                     let idx = idx.to_le_bytes();
-                                    reify_expression(state.ctx_id, self.expr_id, &idx).map_err(
-                                        |e| PolarsError::ComputeError(e.to_string().into()),
-                                    )?;
+                                    reify_expression(state.ctx_id, self.expr_id, &idx)
+                                        .map_err(|err| expr_policy_context(err, &self.expr))?;
                                 }
 
                                 self.process_by_idx(out, state, schema, df, true)
@@ -216,7 +214,7 @@ impl PhysicalExpr for ColumnExpr {
                             if state.policy_check {
                                 let idx = idx.to_le_bytes();
                                 reify_expression(state.ctx_id, self.expr_id, &idx)
-                                    .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+                                    .map_err(|err| expr_policy_context(err, &self.expr))?;
                             }
 
                             return Ok(s);
@@ -21,8 +21,7 @@ impl CountExpr {
             let expr_arg = ExprArgument {
                 argument: Some(Argument::Count(picachv::CountExpr {})),
             };
-            build_expr(ctx_id, expr_arg)
-                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
+            build_expr(ctx_id, expr_arg)?
         } else {
             Uuid::new_v4()
         };
@@ -1,8 +1,10 @@
 use arrow::legacy::is_valid::IsValid;
+use picachv::ExprArgument;
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_utils::idx_vec::IdxVec;
 use rayon::prelude::*;
+use uuid::Uuid;
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::UpdateGroups::WithSeriesLen;
@@ -12,14 +14,35 @@ pub struct FilterExpr {
     pub(crate) input: Arc<dyn PhysicalExpr>,
     pub(crate) by: Arc<dyn PhysicalExpr>,
     expr: Expr,
+    expr_uuid: Uuid,
 }
 
 impl FilterExpr {
-    pub fn new(input: Arc<dyn PhysicalExpr>, by: Arc<dyn PhysicalExpr>, expr: Expr) -> Self {
-        Self { input, by, expr }
+    pub fn new(
+        input: Arc<dyn PhysicalExpr>,
+        by: Arc<dyn PhysicalExpr>,
+        expr: Expr,
+        ctx_id: Uuid,
+        policy_check: bool,
+    ) -> PolarsResult<Self> {
+        let expr_uuid = build_policy_expr(ctx_id, policy_check, || {
+            filter_argument(input.get_uuid(), by.get_uuid())
+        })?;
+
+        Ok(Self {
+            input,
+            by,
+            expr,
+            expr_uuid,
+        })
     }
 }
 
+/// The monitor encoding of `input.filter(by)`.
+pub(crate) fn filter_argument(input: Uuid, by: Uuid) -> ExprArgument {
+    apply_argument("filter", &[], &[input, by])
+}
+
 impl PhysicalExpr for FilterExpr {
     fn as_expression(&self) -> Option<&Expr> {
         Some(&self.expr)
@@ -28,12 +51,17 @@ impl PhysicalExpr for FilterExpr {
         "Filter"
     }
 
+    fn get_uuid(&self) -> Uuid {
+        self.expr_uuid
+    }
+
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
         let s_f = || self.input.evaluate(df, state);
         let predicate_f = || self.by.evaluate(df, state);
 
         let (series, predicate) = POOL.install(|| rayon::join(s_f, predicate_f));
         let (series, predicate) = (series?, predicate?);
+        reify_inputs(state, self.expr_uuid, &self.expr, &[&series, &predicate])?;
 
         series.filter(predicate.bool()?)
     }
@@ -49,6 +77,12 @@ impl PhysicalExpr for FilterExpr {
 
         let (ac_s, ac_predicate) = POOL.install(|| rayon::join(ac_s_f, ac_predicate_f));
         let (mut ac_s, mut ac_predicate) = (ac_s?, ac_predicate?);
+        reify_inputs(
+            state,
+            self.expr_uuid,
+            &self.expr,
+            &[ac_s.series(), ac_predicate.series()],
+        )?;
 
         if ac_predicate.is_aggregated() || ac_s.is_aggregated() {
             // SAFETY: unstable series never lives longer than the iterator.
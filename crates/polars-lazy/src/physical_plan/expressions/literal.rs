@@ -26,7 +26,7 @@ impl LiteralExpr {
                 argument: Some(Argument::Literal(picachv::LiteralExpr {})),
             };
 
-            build_expr(ctx_id, arg).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
+            build_expr(ctx_id, arg)?
         } else {
             Default::default()
         };
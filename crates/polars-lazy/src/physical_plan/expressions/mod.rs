@@ -37,7 +37,7 @@ use polars_io::predicates::PhysicalIoExpr;
 use polars_io::SerWriter;
 use rayon::prelude::*;
 #[cfg(feature = "dynamic_group_by")]
-pub(crate) use rolling::RollingExpr;
+pub(crate) use rolling::{rolling_argument, RollingExpr};
 pub(crate) use slice::*;
 pub(crate) use sort::*;
 pub(crate) use sortby::*;
@@ -49,72 +49,107 @@ pub(crate) use window::*;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 
-pub(crate) fn op_to_binop(op: Operator) -> PolarsResult<picachv::BinaryOperator> {
-    match op {
-        Operator::Eq => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ComparisonOperator(
-                picachv::ComparisonBinaryOperator::Eq.into(),
-            )),
-        }),
-        Operator::NotEq => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ComparisonOperator(
-                picachv::ComparisonBinaryOperator::Ne.into(),
-            )),
-        }),
-        Operator::Lt => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ComparisonOperator(
-                picachv::ComparisonBinaryOperator::Lt.into(),
-            )),
-        }),
-        Operator::Gt => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ComparisonOperator(
-                picachv::ComparisonBinaryOperator::Gt.into(),
-            )),
-        }),
-        Operator::Multiply => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ArithmeticOperator(
-                picachv::ArithmeticBinaryOperator::Mul.into(),
-            )),
-        }),
-        Operator::Plus => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ArithmeticOperator(
-                picachv::ArithmeticBinaryOperator::Add.into(),
-            )),
-        }),
-        Operator::Minus => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ArithmeticOperator(
-                picachv::ArithmeticBinaryOperator::Sub.into(),
-            )),
-        }),
-        Operator::Divide => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ArithmeticOperator(
-                picachv::ArithmeticBinaryOperator::Div.into(),
-            )),
-        }),
-        Operator::And => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::LogicalOperator(
-                picachv::LogicalBinaryOperator::And.into(),
-            )),
-        }),
-        Operator::Or => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::LogicalOperator(
-                picachv::LogicalBinaryOperator::Or.into(),
-            )),
-        }),
-        Operator::LtEq => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ComparisonOperator(
-                picachv::ComparisonBinaryOperator::Le.into(),
-            )),
-        }),
-        Operator::GtEq => Ok(picachv::BinaryOperator {
-            operator: Some(picachv::binary_operator::Operator::ComparisonOperator(
-                picachv::ComparisonBinaryOperator::Ge.into(),
-            )),
-        }),
-        _ => Err(polars_err!(ComputeError: "{op:?} operation not supported")),
+/// The monitor's counterpart of `op`, if it has one. The remaining operators are encoded as an
+/// `Apply`, see [`binary_argument`].
+pub(crate) fn op_to_binop(op: Operator) -> Option<picachv::BinaryOperator> {
+    use picachv::binary_operator::Operator as BinOp;
+    use picachv::{
+        ArithmeticBinaryOperator as Arithmetic, ComparisonBinaryOperator as Comparison,
+        LogicalBinaryOperator as Logical,
+    };
+
+    let operator = match op {
+        Operator::Eq => BinOp::ComparisonOperator(Comparison::Eq.into()),
+        Operator::NotEq => BinOp::ComparisonOperator(Comparison::Ne.into()),
+        Operator::Lt => BinOp::ComparisonOperator(Comparison::Lt.into()),
+        Operator::LtEq => BinOp::ComparisonOperator(Comparison::Le.into()),
+        Operator::Gt => BinOp::ComparisonOperator(Comparison::Gt.into()),
+        Operator::GtEq => BinOp::ComparisonOperator(Comparison::Ge.into()),
+        Operator::Plus => BinOp::ArithmeticOperator(Arithmetic::Add.into()),
+        Operator::Minus => BinOp::ArithmeticOperator(Arithmetic::Sub.into()),
+        Operator::Multiply => BinOp::ArithmeticOperator(Arithmetic::Mul.into()),
+        Operator::Divide => BinOp::ArithmeticOperator(Arithmetic::Div.into()),
+        Operator::And | Operator::LogicalAnd => BinOp::LogicalOperator(Logical::And.into()),
+        Operator::Or | Operator::LogicalOr => BinOp::LogicalOperator(Logical::Or.into()),
+        Operator::EqValidity
+        | Operator::NotEqValidity
+        | Operator::TrueDivide
+        | Operator::FloorDivide
+        | Operator::Modulus
+        | Operator::Xor => return None,
+    };
+
+    Some(picachv::BinaryOperator {
+        operator: Some(operator),
+    })
+}
+
+/// Encode an operation that has no dedicated monitor variant as an `Apply` over its inputs.
+/// The name carries every option that changes the values the operation produces, e.g.
+/// `sort(descending=true, nulls_last=false)`, so that differently parameterized operations are
+/// never encoded alike.
+pub(crate) fn apply_argument(
+    op: &str,
+    params: &[(&str, String)],
+    input_uuids: &[Uuid],
+) -> picachv::ExprArgument {
+    let name = if params.is_empty() {
+        op.to_string()
+    } else {
+        let params = params
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{op}({params})")
+    };
+
+    picachv::ExprArgument {
+        argument: Some(picachv::expr_argument::Argument::Apply(
+            picachv::ApplyExpr {
+                input_uuids: input_uuids
+                    .iter()
+                    .map(|uuid| uuid.to_bytes_le().to_vec())
+                    .collect(),
+                name,
+            },
+        )),
     }
 }
 
+/// Attribute a refusal of the monitor to `expr` and the first column it reads.
+pub(crate) fn expr_policy_context(err: picachv::PicachvError, expr: &Expr) -> PolarsError {
+    let column = expr_to_leaf_column_names_iter(expr).next();
+    PolarsError::from(err).with_policy_context(&expr.to_string(), column.as_deref())
+}
+
+/// Register the encoding produced by `arg` with the monitor if the policy is checked.
+pub(crate) fn build_policy_expr<F>(ctx_id: Uuid, policy_check: bool, arg: F) -> PolarsResult<Uuid>
+where
+    F: FnOnce() -> picachv::ExprArgument,
+{
+    if !policy_check {
+        return Ok(Uuid::nil());
+    }
+    picachv::native::build_expr(ctx_id, arg()).map_err(PolarsError::from)
+}
+
+/// Report to the monitor that the expression `expr_uuid` was applied to `inputs` if the policy
+/// is checked.
+pub(crate) fn reify_inputs(
+    state: &ExecutionState,
+    expr_uuid: Uuid,
+    expr: &Expr,
+    inputs: &[&Series],
+) -> PolarsResult<()> {
+    if !state.policy_check {
+        return Ok(());
+    }
+    let bytes = inputs_as_arrow(inputs)?;
+    picachv::native::reify_expression(state.ctx_id, expr_uuid, &bytes)
+        .map_err(|err| expr_policy_context(err, expr))
+}
+
 #[derive(Clone, Debug)]
 pub(crate) enum AggState {
     /// Already aggregated: `.agg_list(group_tuples`) is called
@@ -1,3 +1,6 @@
+use picachv::ExprArgument;
+use polars_time::Duration;
+
 use super::*;
 
 pub(crate) struct RollingExpr {
@@ -14,6 +17,32 @@ pub(crate) struct RollingExpr {
     pub(crate) out_name: Option<Arc<str>>,
     pub(crate) options: RollingGroupOptions,
     pub(crate) expr: Expr,
+    pub(crate) expr_uuid: Uuid,
+}
+
+/// The monitor encoding of `function.rolling(options)`. The windows are defined by the index
+/// column, which is not an input of the expression and is named in the encoding instead.
+pub(crate) fn rolling_argument(function: Uuid, options: &RollingGroupOptions) -> ExprArgument {
+    let params = [
+        ("index_column", options.index_column.to_string()),
+        ("period", duration_param(&options.period)),
+        ("offset", duration_param(&options.offset)),
+        (
+            "closed",
+            format!("{:?}", options.closed_window).to_lowercase(),
+        ),
+    ];
+    apply_argument("rolling", &params, &[function])
+}
+
+/// `Display` writes integer windows as nanoseconds, which would encode "2i" like "2ns".
+fn duration_param(duration: &Duration) -> String {
+    if duration.parsed_int {
+        let sign = if duration.negative() { "-" } else { "" };
+        format!("{sign}{}i", duration.nanoseconds())
+    } else {
+        duration.to_string()
+    }
 }
 
 impl PhysicalExpr for RollingExpr {
@@ -21,6 +50,10 @@ impl PhysicalExpr for RollingExpr {
         "Rolling"
     }
 
+    fn get_uuid(&self) -> Uuid {
+        self.expr_uuid
+    }
+
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
         let groups_key = format!("{:?}", &self.options);
 
@@ -46,6 +79,7 @@ impl PhysicalExpr for RollingExpr {
             .evaluate_on_groups(df, &groups, state)?
             .finalize();
         polars_ensure!(out.len() == groups.len(), agg_len = out.len(), groups.len());
+        reify_inputs(state, self.expr_uuid, &self.expr, &[&out])?;
         if let Some(name) = &self.out_name {
             out.rename(name.as_ref());
         }
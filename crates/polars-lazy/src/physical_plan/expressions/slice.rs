@@ -1,7 +1,9 @@
+use picachv::ExprArgument;
 use polars_core::prelude::*;
 use polars_core::utils::{slice_offsets, CustomIterTools};
 use polars_core::POOL;
 use rayon::prelude::*;
+use uuid::Uuid;
 use AnyValue::Null;
 
 use crate::physical_plan::state::ExecutionState;
@@ -12,6 +14,12 @@ pub struct SliceExpr {
     pub(crate) offset: Arc<dyn PhysicalExpr>,
     pub(crate) length: Arc<dyn PhysicalExpr>,
     pub(crate) expr: Expr,
+    pub(crate) expr_uuid: Uuid,
+}
+
+/// The monitor encoding of `input.slice(offset, length)`.
+pub(crate) fn slice_argument(input: Uuid, offset: Uuid, length: Uuid) -> ExprArgument {
+    apply_argument("slice", &[], &[input, offset, length])
 }
 
 fn extract_offset(offset: &Series, expr: &Expr) -> PolarsResult<i64> {
@@ -79,6 +87,10 @@ impl PhysicalExpr for SliceExpr {
         "Slice"
     }
 
+    fn get_uuid(&self) -> Uuid {
+        self.expr_uuid
+    }
+
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
         let results = POOL.install(|| {
             [&self.offset, &self.length, &self.input]
@@ -89,6 +101,7 @@ impl PhysicalExpr for SliceExpr {
         let offset = &results[0];
         let length = &results[1];
         let series = &results[2];
+        reify_inputs(state, self.expr_uuid, &self.expr, &[series, offset, length])?;
         let (offset, length) = extract_args(offset, length, &self.expr)?;
 
         Ok(series.slice(offset, length))
@@ -109,6 +122,12 @@ impl PhysicalExpr for SliceExpr {
         let mut ac = results.pop().unwrap();
         let mut ac_length = results.pop().unwrap();
         let mut ac_offset = results.pop().unwrap();
+        reify_inputs(
+            state,
+            self.expr_uuid,
+            &self.expr,
+            &[ac.series(), ac_offset.series(), ac_length.series()],
+        )?;
 
         let groups = ac.groups();
 
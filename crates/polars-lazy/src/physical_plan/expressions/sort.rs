@@ -1,8 +1,10 @@
+use picachv::ExprArgument;
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_ops::chunked_array::ListNameSpaceImpl;
 use polars_utils::idx_vec::IdxVec;
 use rayon::prelude::*;
+use uuid::Uuid;
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -11,18 +13,39 @@ pub struct SortExpr {
     pub(crate) physical_expr: Arc<dyn PhysicalExpr>,
     pub(crate) options: SortOptions,
     expr: Expr,
+    expr_uuid: Uuid,
 }
 
 impl SortExpr {
-    pub fn new(physical_expr: Arc<dyn PhysicalExpr>, options: SortOptions, expr: Expr) -> Self {
-        Self {
+    pub fn new(
+        physical_expr: Arc<dyn PhysicalExpr>,
+        options: SortOptions,
+        expr: Expr,
+        ctx_id: Uuid,
+        policy_check: bool,
+    ) -> PolarsResult<Self> {
+        let expr_uuid = build_policy_expr(ctx_id, policy_check, || {
+            sort_argument(physical_expr.get_uuid(), &options)
+        })?;
+
+        Ok(Self {
             physical_expr,
             options,
             expr,
-        }
+            expr_uuid,
+        })
     }
 }
 
+/// The monitor encoding of sorting `input` by its own values.
+pub(crate) fn sort_argument(input: Uuid, options: &SortOptions) -> ExprArgument {
+    let params = [
+        ("descending", options.descending.to_string()),
+        ("nulls_last", options.nulls_last.to_string()),
+    ];
+    apply_argument("sort", &params, &[input])
+}
+
 /// Map arg_sort result back to the indices on the `GroupIdx`
 pub(crate) fn map_sorted_indices_to_group_idx(sorted_idx: &IdxCa, idx: &[IdxSize]) -> IdxVec {
     sorted_idx
@@ -52,8 +75,14 @@ impl PhysicalExpr for SortExpr {
     fn get_name(&self) -> &str {
         "Sort"
     }
+
+    fn get_uuid(&self) -> Uuid {
+        self.expr_uuid
+    }
+
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
         let series = self.physical_expr.evaluate(df, state)?;
+        reify_inputs(state, self.expr_uuid, &self.expr, &[&series])?;
         series.sort_with(self.options)
     }
 
@@ -65,6 +94,7 @@ impl PhysicalExpr for SortExpr {
         state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
         let mut ac = self.physical_expr.evaluate_on_groups(df, groups, state)?;
+        reify_inputs(state, self.expr_uuid, &self.expr, &[ac.series()])?;
         match ac.agg_state() {
             AggState::AggregatedList(s) => {
                 let ca = s.list().unwrap();
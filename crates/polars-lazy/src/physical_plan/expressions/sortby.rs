@@ -1,7 +1,9 @@
+use picachv::ExprArgument;
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_utils::idx_vec::IdxVec;
 use rayon::prelude::*;
+use uuid::Uuid;
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -11,6 +13,7 @@ pub struct SortByExpr {
     pub(crate) by: Vec<Arc<dyn PhysicalExpr>>,
     pub(crate) expr: Expr,
     pub(crate) sort_options: SortMultipleOptions,
+    expr_uuid: Uuid,
 }
 
 impl SortByExpr {
@@ -19,16 +22,43 @@ impl SortByExpr {
         by: Vec<Arc<dyn PhysicalExpr>>,
         expr: Expr,
         sort_options: SortMultipleOptions,
-    ) -> Self {
-        Self {
+        ctx_id: Uuid,
+        policy_check: bool,
+    ) -> PolarsResult<Self> {
+        let expr_uuid = build_policy_expr(ctx_id, policy_check, || {
+            let by = by.iter().map(|e| e.get_uuid()).collect::<Vec<_>>();
+            sort_by_argument(input.get_uuid(), &by, &sort_options)
+        })?;
+
+        Ok(Self {
             input,
             by,
             expr,
             sort_options,
-        }
+            expr_uuid,
+        })
     }
 }
 
+/// The monitor encoding of sorting `input` by the keys `by`. The inputs are `input` followed
+/// by the keys.
+pub(crate) fn sort_by_argument(
+    input: Uuid,
+    by: &[Uuid],
+    options: &SortMultipleOptions,
+) -> ExprArgument {
+    let descending = prepare_descending(&options.descending, by.len());
+    let params = [
+        ("descending", format!("{descending:?}")),
+        ("nulls_last", options.nulls_last.to_string()),
+        ("maintain_order", options.maintain_order.to_string()),
+    ];
+    let inputs = std::iter::once(input)
+        .chain(by.iter().copied())
+        .collect::<Vec<_>>();
+    apply_argument("sort_by", &params, &inputs)
+}
+
 fn prepare_descending(descending: &[bool], by_len: usize) -> Vec<bool> {
     match (descending.len(), by_len) {
         // Equal length.
@@ -175,14 +205,19 @@ impl PhysicalExpr for SortByExpr {
         "SortBy"
     }
 
+    fn get_uuid(&self) -> Uuid {
+        self.expr_uuid
+    }
+
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
         let series_f = || self.input.evaluate(df, state);
         let descending = prepare_descending(&self.sort_options.descending, self.by.len());
 
-        let (series, sorted_idx) = if self.by.len() == 1 {
+        let (series, sorted_by) = if self.by.len() == 1 {
             let sorted_idx_f = || {
                 let s_sort_by = self.by[0].evaluate(df, state)?;
-                Ok(s_sort_by.arg_sort(SortOptions::from(&self.sort_options)))
+                let sorted_idx = s_sort_by.arg_sort(SortOptions::from(&self.sort_options));
+                PolarsResult::Ok((vec![s_sort_by], sorted_idx))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         } else {
@@ -200,11 +235,16 @@ impl PhysicalExpr for SortByExpr {
                     .collect::<PolarsResult<Vec<_>>>()?;
 
                 let options = self.sort_options.clone().with_order_descendings(descending);
-                s_sort_by[0].arg_sort_multiple(&s_sort_by[1..], &options)
+                let sorted_idx = s_sort_by[0].arg_sort_multiple(&s_sort_by[1..], &options)?;
+                Ok((s_sort_by, sorted_idx))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         };
-        let (sorted_idx, series) = (sorted_idx?, series?);
+        let ((s_sort_by, sorted_idx), series) = (sorted_by?, series?);
+        let inputs = std::iter::once(&series)
+            .chain(&s_sort_by)
+            .collect::<Vec<_>>();
+        reify_inputs(state, self.expr_uuid, &self.expr, &inputs)?;
         polars_ensure!(
             sorted_idx.len() == series.len(),
             expr = self.expr, ComputeError:
@@ -231,6 +271,10 @@ impl PhysicalExpr for SortByExpr {
             .iter()
             .map(|e| e.evaluate_on_groups(df, groups, state))
             .collect::<PolarsResult<Vec<_>>>()?;
+        let inputs = std::iter::once(ac_in.series())
+            .chain(ac_sort_by.iter().map(|ac| ac.series()))
+            .collect::<Vec<_>>();
+        reify_inputs(state, self.expr_uuid, &self.expr, &inputs)?;
         let mut sort_by_s = ac_sort_by
             .iter()
             .map(|s| {
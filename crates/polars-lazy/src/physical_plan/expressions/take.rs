@@ -1,8 +1,10 @@
 use arrow::legacy::utils::CustomIterTools;
+use picachv::ExprArgument;
 use polars_core::chunked_array::builder::get_list_builder;
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
 use polars_ops::prelude::{convert_to_unsigned_index, is_positive_idx_uncertain};
+use uuid::Uuid;
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -12,6 +14,13 @@ pub struct TakeExpr {
     pub(crate) idx: Arc<dyn PhysicalExpr>,
     pub(crate) expr: Expr,
     pub(crate) returns_scalar: bool,
+    pub(crate) expr_uuid: Uuid,
+}
+
+/// The monitor encoding of `input.gather(idx)`.
+pub(crate) fn gather_argument(input: Uuid, idx: Uuid, returns_scalar: bool) -> ExprArgument {
+    let params = [("returns_scalar", returns_scalar.to_string())];
+    apply_argument("gather", &params, &[input, idx])
 }
 
 impl PhysicalExpr for TakeExpr {
@@ -21,6 +30,11 @@ impl PhysicalExpr for TakeExpr {
     fn get_name(&self) -> &str {
         "Take"
     }
+
+    fn get_uuid(&self) -> Uuid {
+        self.expr_uuid
+    }
+
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
         let series = self.phys_expr.evaluate(df, state)?;
         self.finish(df, state, series)
@@ -35,6 +49,12 @@ impl PhysicalExpr for TakeExpr {
     ) -> PolarsResult<AggregationContext<'a>> {
         let mut ac = self.phys_expr.evaluate_on_groups(df, groups, state)?;
         let mut idx = self.idx.evaluate_on_groups(df, groups, state)?;
+        reify_inputs(
+            state,
+            self.expr_uuid,
+            &self.expr,
+            &[ac.series(), idx.series()],
+        )?;
 
         let s_idx = idx.series();
         match s_idx.dtype() {
@@ -105,7 +125,16 @@ impl TakeExpr {
     ) -> PolarsResult<Series> {
         let idx = self.idx.evaluate(df, state)?;
         let idx = convert_to_unsigned_index(&idx, series.len())?;
-        series.take(&idx)
+        let out = series.take(&idx)?;
+        // The input and the indices have different lengths, so the monitor gets the values that
+        // are read at the indices.
+        reify_inputs(
+            state,
+            self.expr_uuid,
+            &self.expr,
+            &[&out, &idx.into_series()],
+        )?;
+        Ok(out)
     }
 
     fn oob_err(&self) -> PolarsResult<()> {
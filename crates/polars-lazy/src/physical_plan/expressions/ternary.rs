@@ -37,7 +37,7 @@ impl TernaryExpr {
                 })),
             };
 
-            build_expr(ctx_id, arg).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
+            build_expr(ctx_id, arg)?
         } else {
             Uuid::nil()
         };
@@ -125,7 +125,7 @@ impl PhysicalExpr for TernaryExpr {
                 .collect::<PolarsResult<Vec<_>>>()?;
 
             reify_expression(state.ctx_id, self.expr_uuid, &mask_bytes)
-                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+                .map_err(|err| expr_policy_context(err, &self.expr))?;
         }
 
         let op_truthy = || self.truthy.evaluate(df, &state);
@@ -1,6 +1,7 @@
 use std::fmt::Write;
 
 use arrow::array::PrimitiveArray;
+use picachv::ExprArgument;
 use polars_core::export::arrow::bitmap::Bitmap;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -27,6 +28,25 @@ pub struct WindowExpr {
     pub(crate) phys_function: Arc<dyn PhysicalExpr>,
     pub(crate) mapping: WindowMapping,
     pub(crate) expr: Expr,
+    pub(crate) expr_uuid: Uuid,
+}
+
+/// The monitor encoding of `function.over(partition_by)`. The inputs are the function followed
+/// by the partition keys.
+pub(crate) fn window_argument(
+    function: Uuid,
+    partition_by: &[Uuid],
+    mapping: WindowMapping,
+) -> ExprArgument {
+    let mapping = match mapping {
+        WindowMapping::GroupsToRows => "groups_to_rows",
+        WindowMapping::Explode => "explode",
+        WindowMapping::Join => "join",
+    };
+    let inputs = std::iter::once(function)
+        .chain(partition_by.iter().copied())
+        .collect::<Vec<_>>();
+    apply_argument("window", &[("mapping", mapping.to_string())], &inputs)
 }
 
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -363,55 +383,15 @@ impl WindowExpr {
             (_, AggState::Literal(_)) => Ok(MapStrategy::Nothing),
         }
     }
-}
-
-impl PhysicalExpr for WindowExpr {
-    fn get_name(&self) -> &str {
-        "Window"
-    }
-    // Note: this was first implemented with expression evaluation but this performed really bad.
-    // Therefore we choose the group_by -> apply -> self join approach
-
-    // This first cached the group_by and the join tuples, but rayon under a mutex leads to deadlocks:
-    // https://github.com/rayon-rs/rayon/issues/592
-    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
-        // This method does the following:
-        // 1. determine group_by tuples based on the group_column
-        // 2. apply an aggregation function
-        // 3. join the results back to the original dataframe
-        //    this stores all group values on the original df size
-        //
-        //      we have several strategies for this
-        //      - 3.1 JOIN
-        //          Use a join for aggregations like
-        //              `sum("foo").over("groups")`
-        //          and explicit `list` aggregations
-        //              `(col("x").sum() * col("y")).list().over("groups")`
-        //
-        //      - 3.2 EXPLODE
-        //          Explicit list aggregations that are followed by `over().flatten()`
-        //          # the fastest method to do things over groups when the groups are sorted.
-        //          # note that it will require an explicit `list()` call from now on.
-        //              `(col("x").sum() * col("y")).list().over("groups").flatten()`
-        //
-        //      - 3.3. MAP to original locations
-        //          This will be done for list aggregations that are not explicitly aggregated as list
-        //              `(col("x").sum() * col("y")).over("groups")
-        //          This can be used to reverse, sort, shuffle etc. the values in a group
-
-        // 4. select the final column and return
-
-        if df.height() == 0 {
-            let field = self.phys_function.to_field(&df.schema())?;
-            return Ok(Series::full_null(field.name(), 0, field.data_type()));
-        }
-
-        let group_by_columns = self
-            .group_by
-            .iter()
-            .map(|e| e.evaluate(df, state))
-            .collect::<PolarsResult<Vec<_>>>()?;
 
+    /// Evaluate the function over the groups of `group_by_columns` and map the result back to
+    /// the rows of `df`.
+    fn evaluate_over(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+        group_by_columns: Vec<Series>,
+    ) -> PolarsResult<Series> {
         // if the keys are sorted
         let sorted_keys = group_by_columns.iter().all(|s| {
             matches!(
@@ -609,6 +589,69 @@ impl PhysicalExpr for WindowExpr {
             },
         }
     }
+}
+
+impl PhysicalExpr for WindowExpr {
+    fn get_name(&self) -> &str {
+        "Window"
+    }
+
+    fn get_uuid(&self) -> Uuid {
+        self.expr_uuid
+    }
+
+    // Note: this was first implemented with expression evaluation but this performed really bad.
+    // Therefore we choose the group_by -> apply -> self join approach
+
+    // This first cached the group_by and the join tuples, but rayon under a mutex leads to deadlocks:
+    // https://github.com/rayon-rs/rayon/issues/592
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        // This method does the following:
+        // 1. determine group_by tuples based on the group_column
+        // 2. apply an aggregation function
+        // 3. join the results back to the original dataframe
+        //    this stores all group values on the original df size
+        //
+        //      we have several strategies for this
+        //      - 3.1 JOIN
+        //          Use a join for aggregations like
+        //              `sum("foo").over("groups")`
+        //          and explicit `list` aggregations
+        //              `(col("x").sum() * col("y")).list().over("groups")`
+        //
+        //      - 3.2 EXPLODE
+        //          Explicit list aggregations that are followed by `over().flatten()`
+        //          # the fastest method to do things over groups when the groups are sorted.
+        //          # note that it will require an explicit `list()` call from now on.
+        //              `(col("x").sum() * col("y")).list().over("groups").flatten()`
+        //
+        //      - 3.3. MAP to original locations
+        //          This will be done for list aggregations that are not explicitly aggregated as list
+        //              `(col("x").sum() * col("y")).over("groups")
+        //          This can be used to reverse, sort, shuffle etc. the values in a group
+
+        // 4. select the final column and return
+
+        if df.height() == 0 {
+            let field = self.phys_function.to_field(&df.schema())?;
+            return Ok(Series::full_null(field.name(), 0, field.data_type()));
+        }
+
+        let group_by_columns = self
+            .group_by
+            .iter()
+            .map(|e| e.evaluate(df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // The keys are moved into the window, the monitor needs them afterwards.
+        let keys = state.policy_check.then(|| group_by_columns.clone());
+        let out = self.evaluate_over(df, state, group_by_columns)?;
+        if let Some(keys) = keys {
+            let inputs = std::iter::once(&out).chain(&keys).collect::<Vec<_>>();
+            reify_inputs(state, self.expr_uuid, &self.expr, &inputs)?;
+        }
+        Ok(out)
+    }
 
     fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
         self.function.to_field(input_schema, Context::Default)
@@ -217,6 +217,12 @@ fn create_physical_expr_inner(
                         }
                     }
 
+                    let expr_uuid = build_policy_expr(state.ctx_id, state.policy_check, || {
+                        let partition_by =
+                            group_by.iter().map(|e| e.get_uuid()).collect::<Vec<_>>();
+                        window_argument(phys_function.get_uuid(), &partition_by, mapping)
+                    })?;
+
                     Ok(Arc::new(WindowExpr {
                         group_by,
                         apply_columns,
@@ -225,16 +231,24 @@ fn create_physical_expr_inner(
                         phys_function,
                         mapping,
                         expr,
+                        expr_uuid,
                     }))
                 },
                 #[cfg(feature = "dynamic_group_by")]
-                WindowType::Rolling(options) => Ok(Arc::new(RollingExpr {
-                    function: function_expr,
-                    phys_function,
-                    out_name,
-                    options,
-                    expr,
-                })),
+                WindowType::Rolling(options) => {
+                    let expr_uuid = build_policy_expr(state.ctx_id, state.policy_check, || {
+                        rolling_argument(phys_function.get_uuid(), &options)
+                    })?;
+
+                    Ok(Arc::new(RollingExpr {
+                        function: function_expr,
+                        phys_function,
+                        out_name,
+                        options,
+                        expr,
+                        expr_uuid,
+                    }))
+                },
             }
         },
         Literal(value) => {
@@ -272,7 +286,9 @@ fn create_physical_expr_inner(
                 phys_expr,
                 options,
                 node_to_expr(expression, expr_arena),
-            )))
+                state.ctx_id,
+                state.policy_check,
+            )?))
         },
         Gather {
             expr,
@@ -281,11 +297,15 @@ fn create_physical_expr_inner(
         } => {
             let phys_expr = create_physical_expr_inner(expr, ctxt, expr_arena, schema, state)?;
             let phys_idx = create_physical_expr_inner(idx, ctxt, expr_arena, schema, state)?;
+            let expr_uuid = build_policy_expr(state.ctx_id, state.policy_check, || {
+                gather_argument(phys_expr.get_uuid(), phys_idx.get_uuid(), returns_scalar)
+            })?;
             Ok(Arc::new(TakeExpr {
                 phys_expr,
                 idx: phys_idx,
                 expr: node_to_expr(expression, expr_arena),
                 returns_scalar,
+                expr_uuid,
             }))
         },
         SortBy {
@@ -302,7 +322,9 @@ fn create_physical_expr_inner(
                 phys_by,
                 node_to_expr(expression, expr_arena),
                 sort_options,
-            )))
+                state.ctx_id,
+                state.policy_check,
+            )?))
         },
         Filter { input, by } => {
             let phys_input = create_physical_expr_inner(input, ctxt, expr_arena, schema, state)?;
@@ -311,7 +333,9 @@ fn create_physical_expr_inner(
                 phys_input,
                 phys_by,
                 node_to_expr(expression, expr_arena),
-            )))
+                state.ctx_id,
+                state.policy_check,
+            )?))
         },
         Agg(agg) => {
             let expr = agg.get_input().first();
@@ -323,6 +347,11 @@ fn create_physical_expr_inner(
                 // TODO!: implement these functions somewhere else
                 // this should not be in the planner.
                 Context::Default if !matches!(agg, AAggExpr::Quantile { .. }) => {
+                    // The aggregation is applied as a function, but reported to the monitor as
+                    // the aggregation that it computes.
+                    let expr_uuid = build_policy_expr(state.ctx_id, state.policy_check, || {
+                        agg_argument(input.get_uuid(), agg.clone().into())
+                    })?;
                     let function = match agg {
                         AAggExpr::Min { propagate_nans, .. } => {
                             let state = *state;
@@ -473,13 +502,16 @@ fn create_physical_expr_inner(
                         },
                     };
                     // FIXME: Aggregation will now turn into an apply expression.
-                    Ok(Arc::new(ApplyExpr::new_minimal(
-                        vec![input],
-                        function,
-                        None,
-                        node_to_expr(expression, expr_arena),
-                        ApplyOptions::ElementWise,
-                    )))
+                    Ok(Arc::new(
+                        ApplyExpr::new_minimal(
+                            vec![input],
+                            function,
+                            None,
+                            node_to_expr(expression, expr_arena),
+                            ApplyOptions::ElementWise,
+                        )
+                        .with_expr_id(expr_uuid),
+                    ))
                 },
                 _ => {
                     if let AAggExpr::Quantile {
@@ -492,7 +524,13 @@ fn create_physical_expr_inner(
                             create_physical_expr_inner(expr, ctxt, expr_arena, schema, state)?;
                         let quantile =
                             create_physical_expr_inner(quantile, ctxt, expr_arena, schema, state)?;
-                        return Ok(Arc::new(AggQuantileExpr::new(input, quantile, interpol)));
+                        return Ok(Arc::new(AggQuantileExpr::new(
+                            input,
+                            quantile,
+                            interpol,
+                            state.ctx_id,
+                            state.policy_check,
+                        )?));
                     }
                     let field = schema
                         .map(|schema| {
@@ -636,11 +674,15 @@ fn create_physical_expr_inner(
             let offset = create_physical_expr_inner(offset, ctxt, expr_arena, schema, state)?;
             let length = create_physical_expr_inner(length, ctxt, expr_arena, schema, state)?;
             polars_ensure!(!(state.has_implode() && matches!(ctxt, Context::Aggregation)), InvalidOperation: "'implode' followed by a slice during aggregation is not allowed");
+            let expr_uuid = build_policy_expr(state.ctx_id, state.policy_check, || {
+                slice_argument(input.get_uuid(), offset.get_uuid(), length.get_uuid())
+            })?;
             Ok(Arc::new(SliceExpr {
                 input,
                 offset,
                 length,
                 expr: node_to_expr(expression, expr_arena),
+                expr_uuid,
             }))
         },
         Explode(expr) => {
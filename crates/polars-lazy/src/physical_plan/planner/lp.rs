@@ -5,9 +5,16 @@ use polars_plan::logical_plan::expr_ir::ExprIR;
 use uuid::Uuid;
 
 use super::super::executors::{self, Executor};
+use super::policy::policy_refusal;
 use super::*;
 use crate::utils::*;
 
+fn policy_unsupported(node: &str) -> PolarsError {
+    polars_err!(
+        InvalidOperation: "{} cannot be tracked when policy checking is enabled", node
+    )
+}
+
 fn partitionable_gb(
     keys: &[ExprIR],
     aggs: &[ExprIR],
@@ -137,6 +144,11 @@ pub fn create_physical_plan(
     use IR::*;
 
     let logical_plan = lp_arena.take(root);
+    if policy_check {
+        if let Some(node) = policy_refusal(&logical_plan) {
+            return Err(policy_unsupported(&node));
+        }
+    }
     match logical_plan {
         #[cfg(feature = "python")]
         PythonScan { options, .. } => Ok(Box::new(executors::PythonScanExec { options })),
@@ -214,7 +226,6 @@ pub fn create_physical_plan(
             )))
         },
         #[allow(unused_variables)]
-        // TODO: Add the policy path to the physical plan!!!!!!!
         Scan {
             paths,
             file_info,
@@ -254,6 +265,7 @@ pub fn create_physical_plan(
                         options: csv_options,
                         predicate,
                         file_options,
+                        with_policy,
                     }))
                 },
                 #[cfg(feature = "ipc")]
@@ -269,6 +281,7 @@ pub fn create_physical_plan(
                     file_options,
                     cloud_options,
                     metadata,
+                    with_policy,
                 })),
                 #[cfg(feature = "parquet")]
                 FileScan::Parquet {
@@ -284,8 +297,7 @@ pub fn create_physical_plan(
                     file_options,
                     metadata,
                     with_policy,
-                    ctx_id,
-                )?)),
+                ))),
                 FileScan::Anonymous { function, .. } => {
                     Ok(Box::new(executors::AnonymousScanExec {
                         function,
@@ -294,6 +306,7 @@ pub fn create_physical_plan(
                         file_info,
                         output_schema,
                         predicate_has_windows: state.has_windows,
+                        with_policy,
                     }))
                 },
             }
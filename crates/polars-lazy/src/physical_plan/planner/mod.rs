@@ -1,6 +1,8 @@
 mod expr;
 mod lp;
+mod policy;
 
 pub(crate) use expr::*;
 pub use lp::*;
 use polars_plan::prelude::*;
+pub(crate) use policy::explain_policy;
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use polars_core::prelude::*;
+use polars_plan::prelude::*;
+
+/// The node that cannot be tracked by the monitor, if `lp` cannot be executed when policy
+/// checking is enabled.
+pub(super) fn policy_refusal(lp: &IR) -> Option<Cow<'static, str>> {
+    match lp {
+        // Only functions that keep or filter the rows of their input can be reported.
+        IR::MapFunction { function, .. }
+            if !matches!(
+                function,
+                FunctionNode::Rename { .. }
+                    | FunctionNode::Rechunk
+                    | FunctionNode::DropNulls { .. }
+                    | FunctionNode::Pipeline { .. }
+            ) =>
+        {
+            Some(format!("'{function}'").into())
+        },
+        IR::ExtContext { .. } => Some("'with_context'".into()),
+        // Without a policy, the scanned data cannot be registered with the monitor.
+        IR::Scan {
+            with_policy: None, ..
+        } => Some("a scan without a policy".into()),
+        #[cfg(feature = "dynamic_group_by")]
+        IR::GroupBy {
+            apply: Some(_),
+            options,
+            ..
+        } if options.dynamic.is_some() || options.rolling.is_some() => {
+            Some("a custom function in a window group-by".into())
+        },
+        _ => None,
+    }
+}
+
+fn policy_source(lp: &IR) -> Cow<'static, str> {
+    match lp {
+        IR::Scan {
+            with_policy: Some(policy),
+            ..
+        } => format!("{policy}").into(),
+        IR::DataFrameScan { df, .. } => match df.get_policy() {
+            Some(DataFramePolicy::File(path)) => format!("{}", path.display()).into(),
+            Some(DataFramePolicy::Bin(_)) => "in-memory".into(),
+            None => "none".into(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// How the executor of `lp` reports to the monitor.
+fn policy_tracking(lp: &IR) -> Cow<'static, str> {
+    use IR::*;
+    if let Some(node) = policy_refusal(lp) {
+        return format!("refused, {node} cannot be tracked").into();
+    }
+    match lp {
+        Scan { .. } | DataFrameScan { .. } => {
+            format!("GetData, policy: {}", policy_source(lp)).into()
+        },
+        #[cfg(feature = "python")]
+        PythonScan { .. } => "skipped, the data is not registered with the monitor".into(),
+        Filter { .. } => "Select".into(),
+        Select { .. } => "Projection".into(),
+        HStack { .. } => "Hstack".into(),
+        GroupBy { .. } => "Aggregate".into(),
+        Join { .. } | HConcat { .. } => "Transform (join)".into(),
+        Union { .. } => "Transform (union)".into(),
+        Sort { .. } => "Transform (reorder)".into(),
+        Slice { .. } | Distinct { .. } => "Transform (filter)".into(),
+        MapFunction { function, .. } => match function {
+            FunctionNode::DropNulls { .. } => "Transform (filter)".into(),
+            FunctionNode::Pipeline { .. } => "reported by the streaming engine".into(),
+            _ => "not reported, rows are unchanged".into(),
+        },
+        Cache { .. } | SimpleProjection { .. } => "not reported, rows are unchanged".into(),
+        Sink { .. } => "written once the result is checked".into(),
+        ExtContext { .. } | Invalid => unreachable!(),
+    }
+}
+
+fn node_name(lp: &IR) -> Cow<'static, str> {
+    match lp {
+        IR::MapFunction { function, .. } if !matches!(function, FunctionNode::Pipeline { .. }) => {
+            format!("{function}").into()
+        },
+        IR::MapFunction { .. } => "streaming".into(),
+        lp => lp.name().into(),
+    }
+}
+
+/// Describe for every node of the plan how it will be reported to the monitor, which policy a
+/// scan registers and which nodes are refused when policy checking is enabled.
+pub(crate) fn explain_policy(root: Node, lp_arena: &Arena<IR>, opt_state: &OptState) -> String {
+    let mut out = String::new();
+    let skipped = opt_state.policy_skipped();
+    if !skipped.is_empty() {
+        writeln!(out, "skipped optimizations: {}", skipped.join(", ")).unwrap();
+    }
+    if opt_state.predicate_pushdown {
+        writeln!(
+            out,
+            "predicate pushdown: predicates are not pushed past joins, group-bys and distincts"
+        )
+        .unwrap();
+    }
+    let mut stack = vec![(root, 0)];
+    while let Some((node, indent)) = stack.pop() {
+        let lp = lp_arena.get(node);
+        writeln!(
+            out,
+            "{:indent$}{}: {}",
+            "",
+            node_name(lp),
+            policy_tracking(lp)
+        )
+        .unwrap();
+        // Push in reverse so that the inputs are printed in order.
+        stack.extend(lp.get_inputs().into_iter().rev().map(|n| (n, indent + 2)));
+    }
+    out
+}
@@ -78,9 +78,9 @@ pub struct ExecutionState {
     pub(super) ctx_id: Uuid,
     pub(super) active_df_uuid: Uuid,
     pub(super) transform: Option<TransformInfo>,
-    // FIXME: Buggy.
-    pub(super) last_used_groupby: (Vec<u64>, Vec<Vec<u64>>), // calling `idx.clone()` causes data corruption.
     pub(super) policy_check: bool,
+    /// The uuids of the dataframes reported by the monitor, recorded for the query metrics.
+    lineage: Option<Arc<Mutex<Vec<Uuid>>>>,
 }
 
 impl ExecutionState {
@@ -102,8 +102,8 @@ impl ExecutionState {
             ctx_id: Uuid::nil(),
             active_df_uuid: Uuid::nil(),
             transform: Default::default(),
-            last_used_groupby: Default::default(),
             policy_check: false,
+            lineage: None,
         }
     }
 
@@ -135,6 +135,24 @@ impl ExecutionState {
         self.node_timer.unwrap().finish()
     }
 
+    /// Toggle this to record the uuids of the dataframes reported by the monitor.
+    pub(crate) fn record_lineage(&mut self) {
+        self.lineage = Some(Default::default())
+    }
+
+    pub(super) fn push_lineage(&self, df_uuid: Uuid) {
+        if let Some(lineage) = &self.lineage {
+            lineage.lock().unwrap().push(df_uuid)
+        }
+    }
+
+    pub(crate) fn take_lineage(&self) -> Vec<Uuid> {
+        self.lineage
+            .as_ref()
+            .map(|lineage| std::mem::take(&mut *lineage.lock().unwrap()))
+            .unwrap_or_default()
+    }
+
     // This is wrong when the U64 overflows which will never happen.
     pub(super) fn should_stop(&self) -> PolarsResult<()> {
         polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
@@ -145,7 +163,7 @@ impl ExecutionState {
         self.stop.clone()
     }
 
-    pub(super) fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
+    pub(crate) fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
         match &self.node_timer {
             None => func(),
             Some(timer) => {
@@ -175,8 +193,8 @@ impl ExecutionState {
             ctx_id: self.ctx_id,
             active_df_uuid: self.active_df_uuid,
             transform: self.transform.clone(),
-            last_used_groupby: self.last_used_groupby.clone(),
             policy_check: self.policy_check,
+            lineage: self.lineage.clone(),
         }
     }
 
@@ -195,8 +213,8 @@ impl ExecutionState {
             ctx_id: self.ctx_id,
             active_df_uuid: self.active_df_uuid,
             transform: self.transform.clone(),
-            last_used_groupby: self.last_used_groupby.clone(),
             policy_check: self.policy_check,
+            lineage: self.lineage.clone(),
         }
     }
 
@@ -259,7 +277,7 @@ impl ExecutionState {
     }
 
     /// More verbose logging
-    pub(super) fn verbose(&self) -> bool {
+    pub(crate) fn verbose(&self) -> bool {
         let flags: StateFlags = self.flags.load(Ordering::Relaxed).into();
         flags.contains(StateFlags::VERBOSE)
     }
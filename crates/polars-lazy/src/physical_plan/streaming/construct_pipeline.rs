@@ -12,6 +12,7 @@ use polars_pipe::pipeline::{
 };
 use polars_pipe::SExecutionContext;
 use polars_plan::prelude::expr_ir::ExprIR;
+use uuid::Uuid;
 
 use crate::physical_plan::planner::{create_physical_expr, ExpressionConversionState};
 use crate::physical_plan::state::ExecutionState;
@@ -44,22 +45,26 @@ impl PhysicalPipedExpr for Wrap {
     fn expression(&self) -> Expr {
         self.0.as_expression().unwrap().clone()
     }
+
+    fn get_uuid(&self) -> PolarsResult<Uuid> {
+        Ok(self.0.get_uuid())
+    }
 }
 
 fn to_physical_piped_expr(
     expr: &ExprIR,
     expr_arena: &Arena<AExpr>,
     schema: Option<&SchemaRef>,
+    policy_ctx_id: Option<Uuid>,
 ) -> PolarsResult<Arc<dyn PhysicalPipedExpr>> {
+    let mut state = ExpressionConversionState::new(false);
+    if let Some(ctx_id) = policy_ctx_id {
+        state.ctx_id = ctx_id;
+        state.policy_check = true;
+    }
     // this is a double Arc<dyn> explore if we can create a single of it.
-    create_physical_expr(
-        expr,
-        Context::Default,
-        expr_arena,
-        schema,
-        &mut ExpressionConversionState::new(false),
-    )
-    .map(|e| Arc::new(Wrap(e)) as Arc<dyn PhysicalPipedExpr>)
+    create_physical_expr(expr, Context::Default, expr_arena, schema, &mut state)
+        .map(|e| Arc::new(Wrap(e)) as Arc<dyn PhysicalPipedExpr>)
 }
 
 fn jit_insert_slice(
@@ -104,9 +109,15 @@ pub(super) fn construct(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     fmt: bool,
+    policy_ctx_id: Option<Uuid>,
 ) -> PolarsResult<Option<Node>> {
     use IR::*;
 
+    let policy_check = policy_ctx_id.is_some();
+    let to_physical = |expr: &ExprIR, expr_arena: &Arena<AExpr>, schema: Option<&SchemaRef>| {
+        to_physical_piped_expr(expr, expr_arena, schema, policy_ctx_id)
+    };
+
     let mut pipelines = Vec::with_capacity(tree.len());
     let mut callbacks = CallBacks::new();
 
@@ -173,13 +184,13 @@ pub(super) fn construct(
                 },
                 PipelineNode::Operator(node) => {
                     operator_nodes.push(node);
-                    let op = get_operator(node, lp_arena, expr_arena, &to_physical_piped_expr)?;
+                    let op = get_operator(node, lp_arena, expr_arena, &to_physical, policy_check)?;
                     operators.push(op);
                 },
                 PipelineNode::Union(node) => {
                     operator_nodes.push(node);
                     jit_insert_slice(node, lp_arena, &mut sink_nodes, operator_offset);
-                    let op = get_operator(node, lp_arena, expr_arena, &to_physical_piped_expr)?;
+                    let op = get_operator(node, lp_arena, expr_arena, &to_physical, policy_check)?;
                     operators.push(op);
                 },
                 PipelineNode::RhsJoin(node) => {
@@ -197,10 +208,11 @@ pub(super) fn construct(
             sink_nodes,
             lp_arena,
             expr_arena,
-            to_physical_piped_expr,
+            to_physical,
             is_verbose,
             &mut sink_cache,
             &mut callbacks,
+            policy_check,
         )?;
         pipelines.push(pipeline);
     }
@@ -234,7 +246,7 @@ pub(super) fn construct(
         .get(insertion_location)
         .schema(lp_arena)
         .into_owned();
-    let pipeline_node = get_pipeline_node(lp_arena, pipelines, schema, original_lp);
+    let pipeline_node = get_pipeline_node(lp_arena, pipelines, schema, original_lp, policy_ctx_id);
     lp_arena.replace(insertion_location, pipeline_node);
 
     Ok(Some(final_sink))
@@ -248,6 +260,10 @@ impl SExecutionContext for ExecutionState {
     fn should_stop(&self) -> PolarsResult<()> {
         ExecutionState::should_stop(self)
     }
+
+    fn policy_ctx_id(&self) -> Option<Uuid> {
+        self.policy_check.then_some(self.ctx_id)
+    }
 }
 
 fn get_pipeline_node(
@@ -255,6 +271,7 @@ fn get_pipeline_node(
     mut pipelines: Vec<PipeLine>,
     schema: SchemaRef,
     original_lp: Option<DslPlan>,
+    policy_ctx_id: Option<Uuid>,
 ) -> IR {
     // create a dummy input as the map function will call the input
     // so we just create a scan that returns an empty df
@@ -270,6 +287,10 @@ fn get_pipeline_node(
         function: FunctionNode::Pipeline {
             function: Arc::new(move |_df: DataFrame| {
                 let mut state = ExecutionState::new();
+                if let Some(ctx_id) = policy_ctx_id {
+                    state.set_ctx_id(ctx_id);
+                    state.set_policy_checking(true);
+                }
                 if state.verbose() {
                     eprintln!("RUN STREAMING PIPELINE");
                     eprintln!("{:?}", &pipelines)
@@ -1,6 +1,7 @@
 use polars_core::prelude::*;
 use polars_pipe::pipeline::swap_join_order;
 use polars_plan::prelude::*;
+use uuid::Uuid;
 
 use super::checks::*;
 use crate::physical_plan::streaming::tree::*;
@@ -96,6 +97,7 @@ fn insert_slice(
     state.operators_sinks.push(PipelineNode::Sink(node));
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn insert_streaming_nodes(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -106,6 +108,8 @@ pub(crate) fn insert_streaming_nodes(
     // to streaming
     allow_partial: bool,
     row_estimate: bool,
+    // The monitor context if the pipelines must report to the monitor.
+    policy_ctx_id: Option<Uuid>,
 ) -> PolarsResult<bool> {
     scratch.clear();
 
@@ -163,6 +167,11 @@ pub(crate) fn insert_streaming_nodes(
     // keep the counter global so that the order will match traversal order
     let mut execution_id = 0;
 
+    // Only sources, filters and projections report to the monitor from the streaming engine, the
+    // group-by, join, sort and slice sinks do not. Under policy checking these nodes break the
+    // pipeline, so that the executors of the default engine report them instead.
+    let sinks_allowed = policy_ctx_id.is_none();
+
     use IR::*;
     while let Some(StackFrame {
         node: mut root,
@@ -189,7 +198,7 @@ pub(crate) fn insert_streaming_nodes(
                 state.operators_sinks.push(PipelineNode::Operator(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
-            Slice { input, offset, .. } if *offset >= 0 => {
+            Slice { input, offset, .. } if *offset >= 0 && sinks_allowed => {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Sink(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
@@ -204,7 +213,10 @@ pub(crate) fn insert_streaming_nodes(
                 by_column,
                 slice,
                 sort_options,
-            } if is_streamable_sort(slice, sort_options) && all_column(by_column, expr_arena) => {
+            } if sinks_allowed
+                && is_streamable_sort(slice, sort_options)
+                && all_column(by_column, expr_arena) =>
+            {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Sink(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
@@ -273,7 +285,7 @@ pub(crate) fn insert_streaming_nodes(
                 input_right,
                 options,
                 ..
-            } if streamable_join(&options.args) => {
+            } if sinks_allowed && streamable_join(&options.args) => {
                 let input_left = *input_left;
                 let input_right = *input_right;
                 state.streamable = true;
@@ -327,7 +339,7 @@ pub(crate) fn insert_streaming_nodes(
                         ..
                     },
                 ..
-            } if *offset >= 0 => {
+            } if *offset >= 0 && sinks_allowed => {
                 insert_slice(root, *offset, *len as IdxSize, lp_arena, &mut state);
                 state.streamable = true;
                 let Union { inputs, .. } = lp_arena.get(root) else {
@@ -371,7 +383,8 @@ pub(crate) fn insert_streaming_nodes(
                 }
             },
             Distinct { input, options }
-                if !options.maintain_order
+                if sinks_allowed
+                    && !options.maintain_order
                     && !matches!(options.keep_strategy, UniqueKeepStrategy::None) =>
             {
                 state.streamable = true;
@@ -415,7 +428,7 @@ pub(crate) fn insert_streaming_nodes(
                 }
                 let input_schema = lp_arena.get(*input).schema(lp_arena);
                 #[allow(unused_mut)]
-                let mut can_stream = true;
+                let mut can_stream = sinks_allowed;
 
                 #[cfg(feature = "dynamic_group_by")]
                 {
@@ -485,7 +498,8 @@ pub(crate) fn insert_streaming_nodes(
     let mut inserted = false;
     for tree in pipeline_trees {
         if is_valid_tree(&tree)
-            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt)?.is_some()
+            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt, policy_ctx_id)?
+                .is_some()
         {
             inserted = true;
         }
@@ -1,3 +1,5 @@
+#[cfg(feature = "range_join")]
+pub use polars_ops::prelude::{InequalityOperator, RangeJoinOptions};
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
@@ -13,6 +15,7 @@ pub use polars_plan::prelude::IpcWriterOptions;
 pub use polars_plan::prelude::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_plan::prelude::ParquetWriteOptions;
+pub use polars_plan::prelude::ScanPolicy;
 pub(crate) use polars_plan::prelude::*;
 #[cfg(feature = "rolling_window")]
 pub use polars_time::{prelude::RollingOptions, Duration};
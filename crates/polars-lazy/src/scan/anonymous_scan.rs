@@ -11,6 +11,7 @@ pub struct ScanArgsAnonymous {
     pub n_rows: Option<usize>,
     pub row_index: Option<RowIndex>,
     pub name: &'static str,
+    pub with_policy: Option<ScanPolicy>,
 }
 
 impl Default for ScanArgsAnonymous {
@@ -22,6 +23,7 @@ impl Default for ScanArgsAnonymous {
             schema: None,
             row_index: None,
             name: "ANONYMOUS SCAN",
+            with_policy: None,
         }
     }
 }
@@ -37,6 +39,7 @@ impl LazyFrame {
             args.skip_rows,
             args.n_rows,
             args.name,
+            args.with_policy,
         )?
         .build()
         .into();
@@ -37,6 +37,7 @@ pub struct LazyCsvReader {
     raise_if_empty: bool,
     n_threads: Option<usize>,
     decimal_float: bool,
+    with_policy: Option<ScanPolicy>,
 }
 
 #[cfg(feature = "csv")]
@@ -73,6 +74,7 @@ impl LazyCsvReader {
             truncate_ragged_lines: false,
             n_threads: None,
             decimal_float: false,
+            with_policy: None,
         }
     }
 
@@ -239,6 +241,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Attach a policy to this scan. The policy is resolved for the scanned file and registered
+    /// with the monitor when the scan is executed with policy checking enabled.
+    #[must_use]
+    pub fn with_policy(mut self, policy: Option<ScanPolicy>) -> Self {
+        self.with_policy = policy;
+        self
+    }
+
     /// Modify a schema before we run the lazy scanning.
     ///
     /// Important! Run this function latest in the builder!
@@ -316,6 +326,7 @@ impl LazyFileListReader for LazyCsvReader {
             self.truncate_ragged_lines,
             self.n_threads,
             self.decimal_float,
+            self.with_policy,
         )?
         .build()
         .into();
@@ -14,6 +14,7 @@ pub struct ScanArgsIpc {
     pub row_index: Option<RowIndex>,
     pub memory_map: bool,
     pub cloud_options: Option<CloudOptions>,
+    pub with_policy: Option<ScanPolicy>,
 }
 
 impl Default for ScanArgsIpc {
@@ -25,6 +26,7 @@ impl Default for ScanArgsIpc {
             row_index: None,
             memory_map: true,
             cloud_options: Default::default(),
+            with_policy: None,
         }
     }
 }
@@ -78,6 +80,7 @@ impl LazyFileListReader for LazyIpcReader {
             args.row_index,
             args.rechunk,
             args.cloud_options,
+            args.with_policy,
         )?
         .build()
         .into();
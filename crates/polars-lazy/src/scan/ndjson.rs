@@ -6,7 +6,7 @@ use polars_core::prelude::*;
 use polars_io::RowIndex;
 
 use super::*;
-use crate::prelude::{LazyFrame, ScanArgsAnonymous};
+use crate::prelude::{LazyFrame, ScanArgsAnonymous, ScanPolicy};
 
 #[derive(Clone)]
 pub struct LazyJsonLineReader {
@@ -20,6 +20,7 @@ pub struct LazyJsonLineReader {
     pub(crate) infer_schema_length: Option<usize>,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) with_policy: Option<ScanPolicy>,
 }
 
 impl LazyJsonLineReader {
@@ -39,6 +40,7 @@ impl LazyJsonLineReader {
             infer_schema_length: Some(100),
             ignore_errors: false,
             n_rows: None,
+            with_policy: None,
         }
     }
     /// Add a row index column.
@@ -89,6 +91,14 @@ impl LazyJsonLineReader {
         self.batch_size = batch_size;
         self
     }
+
+    /// Attach a policy to this scan. The policy is resolved for the scanned file and registered
+    /// with the monitor when the scan is executed with policy checking enabled.
+    #[must_use]
+    pub fn with_policy(mut self, policy: Option<ScanPolicy>) -> Self {
+        self.with_policy = policy;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -99,6 +109,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             n_rows: self.n_rows,
             row_index: self.row_index.clone(),
             schema: self.schema.read().unwrap().clone(),
+            with_policy: self.with_policy.clone(),
             ..ScanArgsAnonymous::default()
         };
 
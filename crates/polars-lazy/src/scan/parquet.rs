@@ -18,7 +18,7 @@ pub struct ScanArgsParquet {
     pub cloud_options: Option<CloudOptions>,
     pub use_statistics: bool,
     pub hive_options: HiveOptions,
-    pub with_policy: Option<PathBuf>,
+    pub with_policy: Option<ScanPolicy>,
 }
 
 impl Default for ScanArgsParquet {
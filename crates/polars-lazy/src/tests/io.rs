@@ -36,6 +36,387 @@ fn test_parquet_exec() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_policy_registered_on_execute() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+    let args = ScanArgsParquet {
+        with_policy: Some(ScanPolicy::File("does-not-exist.policy".into())),
+        ..Default::default()
+    };
+
+    // The policy is only registered by a policy-checked execution, so planning does not
+    // touch it.
+    let out = LazyFrame::scan_parquet(FOODS_PARQUET, args)?
+        .filter(col("category").eq(lit("seafood")))
+        .collect()?;
+    assert_eq!(out.shape(), (8, 4));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_per_file_policy() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+    let args = ScanArgsParquet {
+        with_policy: Some(ScanPolicy::HiveTemplate(
+            "policies/year={year}.policy".into(),
+        )),
+        ..Default::default()
+    };
+    let q = LazyFrame::scan_parquet(FOODS_PARQUET, args)?;
+    let explained = q.explain_policy()?;
+    assert!(explained.contains("policy: policies/year={year}.policy"));
+
+    // The file is not partitioned by `year`, so its policy cannot be resolved.
+    let res = q.set_policy_checking(true).collect();
+    assert!(matches!(res, Err(PolarsError::ComputeError(_))));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_ipc_sibling_policies() -> PolarsResult<()> {
+    use std::cell::RefCell;
+
+    use uuid::Uuid;
+
+    use crate::physical_plan::executors::register_file_policies;
+
+    let dir = std::env::temp_dir().join("polars_ipc_sibling_policies");
+    std::fs::create_dir_all(&dir)?;
+    let mut paths = vec![];
+    for (name, values) in [("a", [1i64, 2, 3]), ("b", [4, 5, 6])] {
+        let path = dir.join(format!("{name}.ipc"));
+        let mut df = df![
+            "x" => values,
+        ]?;
+        IpcWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+        sum_only_policy(&["x"], "x", 3).to_parquet(path.with_extension("policy"))?;
+        paths.push(path);
+    }
+    let policy = ScanPolicy::Sibling {
+        extension: "policy".into(),
+    };
+
+    let args = ScanArgsIpc {
+        with_policy: Some(policy.clone()),
+        ..Default::default()
+    };
+    let q = LazyFrame::scan_ipc(dir.join("*.ipc"), args)?.filter(col("x").gt(lit(2)));
+
+    // Both files only release `x` summed up.
+    let out = q
+        .clone()
+        .select([col("x").sum()])
+        .set_ctx_id(open_monitor_context())
+        .set_policy_checking(true)
+        .collect()?;
+    assert_eq!(out, df![ "x" => [18i64] ]?);
+    let res = q
+        .select([col("x")])
+        .set_ctx_id(open_monitor_context())
+        .set_policy_checking(true)
+        .collect();
+    assert_policy_violation(res, "finalize");
+
+    // Every file is covered by its own policy and the part of the predicate mask over its rows.
+    let mask = BooleanChunked::from_slice("", &[false, false, true, true, true, true]);
+    let registered = RefCell::new(vec![]);
+    register_file_policies(
+        open_monitor_context(),
+        &policy,
+        &paths,
+        &[3, 3],
+        Some(&mask),
+        |policy, mask| {
+            let mask = mask.map(|mask| mask.into_iter().collect::<Vec<_>>());
+            registered.borrow_mut().push((policy.to_path_buf(), mask));
+            Ok(Uuid::new_v4())
+        },
+    )?;
+    assert_eq!(
+        registered.into_inner(),
+        [
+            (
+                dir.join("a.policy"),
+                Some(vec![Some(false), Some(false), Some(true)])
+            ),
+            (dir.join("b.policy"), Some(vec![Some(true); 3])),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_sibling_policies_with_n_rows() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_parquet_sibling_policies");
+    std::fs::create_dir_all(&dir)?;
+    for (name, values) in [("a", [1i64, 2, 3]), ("b", [4, 5, 6])] {
+        let path = dir.join(format!("{name}.parquet"));
+        let mut df = df![
+            "x" => values,
+        ]?;
+        ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+        sum_only_policy(&["x"], "x", 3).to_parquet(path.with_extension("policy"))?;
+    }
+    let args = ScanArgsParquet {
+        n_rows: Some(4),
+        with_policy: Some(ScanPolicy::Sibling {
+            extension: "policy".into(),
+        }),
+        ..Default::default()
+    };
+
+    // Only the first row of `b` is within `n_rows`, but its policy covers all of its rows.
+    let out = LazyFrame::scan_parquet(dir.join("*.parquet"), args)?
+        .filter(col("x").gt(lit(1)))
+        .select([col("x").sum()])
+        .set_ctx_id(open_monitor_context())
+        .set_policy_checking(true)
+        .collect()?;
+    assert_eq!(out, df![ "x" => [9i64] ]?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "cloud"))]
+fn test_parquet_cloud_policy() -> PolarsResult<()> {
+    let dir = std::env::temp_dir();
+    let path = dir.join("polars_parquet_cloud_policy.parquet");
+    let policy_path = dir.join("polars_parquet_cloud_policy.policy");
+    let mut df = df![
+        "a" => [1, 2, 3],
+    ]?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+    std::fs::write(&policy_path, sum_only_policy(&["a"], "a", 3).to_bytes())?;
+
+    let scan = |policy: &std::path::Path| {
+        let args = ScanArgsParquet {
+            with_policy: Some(ScanPolicy::File(
+                format!("file://{}", policy.display()).into(),
+            )),
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(format!("file://{}", path.display()), args)
+    };
+
+    // The policy is fetched from the object store of the scan.
+    let out = scan(&policy_path)?
+        .filter(col("a").gt(lit(1)))
+        .select([col("a").sum()])
+        .set_ctx_id(open_monitor_context())
+        .set_policy_checking(true)
+        .collect()?;
+    assert_eq!(out, df![ "a" => [5] ]?);
+    let res = scan(&policy_path)?
+        .select([col("a")])
+        .set_ctx_id(open_monitor_context())
+        .set_policy_checking(true)
+        .collect();
+    assert_policy_violation(res, "finalize");
+
+    let res = scan(&dir.join("does-not-exist.policy"))?
+        .set_ctx_id(open_monitor_context())
+        .set_policy_checking(true)
+        .collect();
+    assert!(res.is_err());
+
+    std::fs::remove_file(&path)?;
+    std::fs::remove_file(&policy_path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json",
+    feature = "streaming"
+))]
+fn test_policy_checked_sinks() -> PolarsResult<()> {
+    let dir = std::env::temp_dir();
+    let df = df![
+        "a" => [1i64, 2, 3],
+    ]?;
+    let expected = df![
+        "a" => [5i64],
+    ]?;
+    let q = |policy: DataFramePolicy, a: Expr| {
+        LazyFrame::from_df_with_policy(df.clone(), policy)
+            .filter(col("a").gt(lit(1)))
+            .select([a])
+            .set_ctx_id(open_monitor_context())
+            .set_policy_checking(true)
+    };
+    let valid = DataFramePolicy::Bin(Arc::from(sum_only_policy(&["a"], "a", 3).to_bytes()));
+    let missing = DataFramePolicy::File(Arc::new(dir.join("does-not-exist.policy")));
+
+    // `a` may only be written summed up.
+    let released = || q(valid.clone(), col("a").sum());
+    let parquet = dir.join("polars_policy_checked_sink.parquet");
+    released().sink_parquet(parquet.clone(), Default::default())?;
+    let out = LazyFrame::scan_parquet(&parquet, Default::default())?.collect()?;
+    assert_eq!(out, expected);
+
+    let ipc = dir.join("polars_policy_checked_sink.ipc");
+    released().sink_ipc(ipc.clone(), Default::default())?;
+    let out = LazyFrame::scan_ipc(&ipc, Default::default())?.collect()?;
+    assert_eq!(out, expected);
+
+    let csv = dir.join("polars_policy_checked_sink.csv");
+    released().sink_csv(csv.clone(), Default::default())?;
+    let out = LazyCsvReader::new(&csv).finish()?.collect()?;
+    assert_eq!(out, expected);
+
+    let ndjson = dir.join("polars_policy_checked_sink.ndjson");
+    released().sink_json(ndjson.clone(), Default::default())?;
+    let out = LazyJsonLineReader::new(&ndjson).finish()?.collect()?;
+    assert_eq!(out, expected);
+
+    // The result is checked before anything is written.
+    let rejected = dir.join("polars_policy_checked_sink_rejected.parquet");
+    let _ = std::fs::remove_file(&rejected);
+    let res = q(valid, col("a")).sink_parquet(rejected.clone(), Default::default());
+    assert_policy_violation(res, "finalize");
+    assert!(!rejected.exists());
+    let res = q(missing, col("a")).sink_parquet(rejected.clone(), Default::default());
+    assert!(matches!(res, Err(PolarsError::IO { .. })));
+    assert!(!rejected.exists());
+
+    for path in [parquet, ipc, csv, ndjson] {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_scan_policy_mask_covers_all_rows() -> PolarsResult<()> {
+    use crate::physical_plan::executors::limit_scan_rows;
+
+    let df = df![
+        "a" => [1, 2, 3, 4, 5],
+    ]?;
+
+    // Every row is kept, so the policy applies as is.
+    let mut out = df.clone();
+    assert!(limit_scan_rows(&mut out, None, |_| Ok(None))?.is_none());
+    assert!(limit_scan_rows(&mut out, Some(10), |_| Ok(None))?.is_none());
+    assert_eq!(out, df);
+
+    // The rows beyond `n_rows` are masked out.
+    let mut out = df.clone();
+    let mask = limit_scan_rows(&mut out, Some(3), |_| Ok(None))?.unwrap();
+    assert_eq!(out, df.head(Some(3)));
+    assert_eq!(
+        mask.into_iter().collect::<Vec<_>>(),
+        [Some(true), Some(true), Some(true), Some(false), Some(false)]
+    );
+
+    // The predicate only sees the rows within `n_rows`.
+    let mut out = df.clone();
+    let mask = limit_scan_rows(&mut out, Some(3), |df| {
+        let mask = df.column("a")?.gt(1)?;
+        *df = df.filter(&mask)?;
+        Ok(Some(mask))
+    })?
+    .unwrap();
+    assert_eq!(out, df![ "a" => [2, 3] ]?);
+    assert_eq!(
+        mask.into_iter().collect::<Vec<_>>(),
+        [
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "ipc", feature = "json"))]
+fn test_policy_checked_file_scans() -> PolarsResult<()> {
+    let dir = std::env::temp_dir();
+    let csv = dir.join("polars_policy_checked_scan.csv");
+    let ipc = dir.join("polars_policy_checked_scan.ipc");
+    let ndjson = dir.join("polars_policy_checked_scan.ndjson");
+    let policy = dir.join("polars_policy_checked_scan.policy");
+    let mut df = df![
+        "a" => [1i64, 2, 3, 4, 5],
+        "b" => ["a", "b", "c", "d", "e"],
+    ]?;
+    CsvWriter::new(std::fs::File::create(&csv)?).finish(&mut df)?;
+    IpcWriter::new(std::fs::File::create(&ipc)?).finish(&mut df)?;
+    JsonWriter::new(std::fs::File::create(&ndjson)?)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut df)?;
+    sum_only_policy(&["a", "b"], "a", 5).to_parquet(&policy)?;
+
+    let scans = |policy: Option<ScanPolicy>| -> PolarsResult<Vec<LazyFrame>> {
+        Ok(vec![
+            LazyCsvReader::new(&csv)
+                .with_n_rows(Some(3))
+                .with_policy(policy.clone())
+                .finish()?,
+            LazyFrame::scan_ipc(
+                &ipc,
+                ScanArgsIpc {
+                    n_rows: Some(3),
+                    with_policy: policy.clone(),
+                    ..Default::default()
+                },
+            )?,
+            LazyJsonLineReader::new(&ndjson)
+                .with_n_rows(Some(3))
+                .with_policy(policy)
+                .finish()?,
+        ])
+    };
+
+    // The data files share their stem, so they resolve to the same sibling policy.
+    let sibling = ScanPolicy::Sibling {
+        extension: "policy".into(),
+    };
+    for (q, unprotected) in scans(Some(sibling))?.into_iter().zip(scans(None)?) {
+        let q = q.filter(col("a").gt(lit(1)));
+        let checked = |q: LazyFrame| {
+            q.set_ctx_id(open_monitor_context())
+                .set_policy_checking(true)
+                .collect()
+        };
+
+        // The file is read in full for the monitor, which must not change the result.
+        let out = checked(q.clone().select([col("b"), col("a").sum()]))?;
+        assert_eq!(
+            out,
+            df![
+                "b" => ["b", "c"],
+                "a" => [5i64, 5],
+            ]?
+        );
+        assert_policy_violation(checked(q.select([col("a")])), "finalize");
+
+        let res = checked(unprotected);
+        assert!(matches!(res, Err(PolarsError::InvalidOperation(_))));
+    }
+
+    for path in [csv, ipc, ndjson, policy] {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
 #[test]
 #[cfg(all(feature = "parquet", feature = "is_between"))]
 fn test_parquet_statistics_no_skip() {
@@ -417,6 +798,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             row_index: None,
             memory_map: true,
             cloud_options: None,
+            with_policy: None,
         },
     )?
     .collect()?;
@@ -186,3 +186,46 @@ pub(crate) fn get_df() -> DataFrame {
         .unwrap();
     df
 }
+
+/// Open a new context in the policy monitor. The monitor is shared by all tests and may
+/// already be initialized.
+pub(crate) fn open_monitor_context() -> uuid::Uuid {
+    let _ = picachv::native::init_monitor();
+    picachv::native::open_new().unwrap()
+}
+
+/// A policy for a frame of `height` rows with the given `columns`. The cells of `protected` may
+/// only leave a query summed up, the cells of the other columns are public.
+pub(crate) fn sum_only_policy(
+    columns: &[&str],
+    protected: &str,
+    height: usize,
+) -> picachv::PolicyGuardedDataFrame {
+    let policies = |name: &str| {
+        let policy = if name == protected {
+            r#"{"PolicyDeclassify":{"label":{"PolicyAgg":{"ops":["Sum"]}},"next":"PolicyClean"}}"#
+        } else {
+            r#""PolicyClean""#
+        };
+        format!(r#"{{"policies":[{}]}}"#, vec![policy; height].join(","))
+    };
+    let columns_json = columns
+        .iter()
+        .map(|name| policies(name))
+        .collect::<Vec<_>>();
+    let json = format!(
+        r#"{{"schema":{columns:?},"columns":[{}]}}"#,
+        columns_json.join(",")
+    );
+    picachv::PolicyGuardedDataFrame::from_json(&json).unwrap()
+}
+
+/// Assert that the monitor refused a query at `operator`.
+pub(crate) fn assert_policy_violation<T: std::fmt::Debug>(res: PolarsResult<T>, operator: &str) {
+    match res {
+        Err(PolarsError::PolicyViolation(violation)) => {
+            assert_eq!(violation.operator.as_deref(), Some(operator))
+        },
+        res => panic!("expected a policy violation, got {res:?}"),
+    }
+}
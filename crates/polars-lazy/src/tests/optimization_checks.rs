@@ -548,3 +548,97 @@ fn test_flatten_unions() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_policy_checking_optimizations() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let categories = df![
+        "category" => ["fruit", "seafood"],
+    ]?;
+    let q = scan_foods_parquet(false)
+        .join(
+            categories.lazy(),
+            [col("category")],
+            [col("category")],
+            JoinType::Inner.into(),
+        )
+        .filter(col("calories").gt(lit(50)));
+    assert!(predicate_at_scan(q.clone()));
+    let sliced = scan_foods_parquet(false).limit(3);
+    assert!(slice_at_scan(sliced.clone()));
+
+    // Policy checking keeps the filter above the join and the slice out of the scan.
+    assert!(!predicate_at_scan(q.set_policy_checking(true)));
+    assert!(!slice_at_scan(sliced.set_policy_checking(true)));
+
+    // Duplicate scans are not cached.
+    let q = scan_foods_parquet(false)
+        .join(
+            scan_foods_parquet(false),
+            [col("category")],
+            [col("category")],
+            JoinType::Inner.into(),
+        )
+        .set_policy_checking(true);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(!(&lp_arena)
+        .iter(lp)
+        .any(|(_, lp)| matches!(lp, IR::Cache { .. })));
+
+    // Predicates are still pushed past the nodes that keep the rows.
+    let q = scan_foods_parquet(false)
+        .sort(["category"], Default::default())
+        .filter(col("calories").gt(lit(50)))
+        .set_policy_checking(true);
+    assert!(predicate_at_scan(q));
+
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder() -> PolarsResult<()> {
+    let facts = df![
+        "id" => (0..100).collect::<Vec<i32>>(),
+        "a_id" => (0..100).map(|i| i % 50).collect::<Vec<i32>>(),
+        "b_id" => (0..100).map(|i| i % 3).collect::<Vec<i32>>(),
+    ]?;
+    let a = df![
+        "a_id" => (0..50).collect::<Vec<i32>>(),
+        "a_val" => (0..50).map(|i| i * 10).collect::<Vec<i32>>(),
+    ]?;
+    let b = df![
+        "b_id" => [0, 1, 2],
+        "b_val" => ["x", "y", "z"],
+    ]?;
+    let q = facts
+        .lazy()
+        .inner_join(a.lazy(), col("a_id"), col("a_id"))
+        .inner_join(b.lazy(), col("b_id"), col("b_id"));
+
+    // The smallest relation is joined first and the written column order is restored.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q
+        .clone()
+        .with_join_reorder(true)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    let IR::SimpleProjection { input, .. } = lp_arena.get(lp) else {
+        panic!("expected a projection")
+    };
+    let IR::Join { input_right, .. } = lp_arena.get(*input) else {
+        panic!("expected a join")
+    };
+    assert!(matches!(
+        lp_arena.get(*input_right),
+        IR::DataFrameScan { df, .. } if df.height() == 50
+    ));
+
+    let expected = q.clone().sort(["id"], Default::default()).collect()?;
+    let out = q
+        .with_join_reorder(true)
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
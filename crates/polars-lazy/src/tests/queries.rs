@@ -1188,6 +1188,57 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "range_join")]
+#[test]
+fn test_join_where() -> PolarsResult<()> {
+    let intervals = df![
+        "id" => [0, 1, 2],
+        "start" => [1, 4, 10],
+        "end" => [5, 8, 11],
+    ]?;
+    let events = df![
+        "id" => [0, 1, 2, 3, 4],
+        "ts" => [6i64, 2, 20, 4, 7],
+    ]?;
+    let predicates = [
+        col("start").lt_eq(col("ts")),
+        col("ts").lt(col("end")),
+        col("id").neq(col("id_right")),
+    ];
+
+    let q = intervals
+        .clone()
+        .lazy()
+        .join_where(events.clone().lazy(), &predicates)?;
+    let plan = q.describe_plan();
+    assert!(plan.contains("RANGE JOIN"));
+    assert!(!plan.contains("CROSS JOIN"));
+
+    let out = q.clone().collect()?;
+    let expected = intervals
+        .lazy()
+        .cross_join(events.lazy())
+        .filter(
+            predicates
+                .iter()
+                .cloned()
+                .reduce(|acc, e| acc.and(e))
+                .unwrap(),
+        )
+        .collect()?;
+    assert!(out.equals(&expected));
+    assert_eq!(
+        Vec::from(out.column("id_right")?.i32()?),
+        &[Some(1), Some(3), Some(0), Some(3), Some(4)]
+    );
+
+    // The right key is projected under its name after the join.
+    let out = q.select([col("id"), col("ts")]).collect()?;
+    assert_eq!(out.get_column_names(), &["id", "ts"]);
+    assert_eq!(out.height(), 5);
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1929,3 +1980,560 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+/// Create the physical expression of `expr` with policy checking enabled, so that it and all of
+/// its inputs are registered with a new monitor context.
+fn create_policy_checked_expr(
+    expr: Expr,
+    ctxt: Context,
+    schema: &SchemaRef,
+) -> PolarsResult<Arc<dyn PhysicalExpr>> {
+    use picachv::native::{init_monitor, open_new};
+
+    use crate::physical_plan::planner::{create_physical_expr, ExpressionConversionState};
+
+    // The monitor is shared by all tests and may already be initialized.
+    let _ = init_monitor();
+    let mut arena = Arena::with_capacity(8);
+    let expr = to_expr_ir(expr, &mut arena);
+    let mut state = ExpressionConversionState::new(false);
+    state.set_ctx_id(open_new()?);
+    state.policy_check = true;
+    create_physical_expr(&expr, ctxt, &arena, Some(schema), &mut state)
+}
+
+/// The name and the inputs of an `Apply` encoding.
+fn apply_parts(arg: picachv::ExprArgument) -> (String, Vec<uuid::Uuid>) {
+    match arg.argument {
+        Some(picachv::expr_argument::Argument::Apply(apply)) => {
+            let inputs = apply
+                .input_uuids
+                .iter()
+                .map(|uuid| uuid::Uuid::from_slice_le(uuid).unwrap())
+                .collect();
+            (apply.name, inputs)
+        },
+        arg => panic!("expected an apply encoding, got {arg:?}"),
+    }
+}
+
+#[test]
+fn test_policy_check_builds_shaping_expressions() -> PolarsResult<()> {
+    use uuid::Uuid;
+
+    use crate::physical_plan::expressions::*;
+
+    let schema: SchemaRef = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int32),
+        Field::new("b", DataType::Int32),
+    ]));
+    let exprs = [
+        col("a").sort(Default::default()),
+        col("a").sort_by([col("b")], Default::default()),
+        col("a").gather(col("b")),
+        col("a").slice(0, 2),
+        col("a").filter(col("b").gt(lit(1))),
+        col("a").sum().over([col("b")]),
+    ];
+    for expr in exprs {
+        let phys_expr = create_policy_checked_expr(expr, Context::Default, &schema)?;
+        assert!(!phys_expr.get_uuid().is_nil());
+    }
+
+    let (a, b, c) = (Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3));
+    let descending = SortOptions::default().with_order_descending(true);
+    assert_eq!(
+        apply_parts(sort_argument(a, &descending)),
+        ("sort(descending=true, nulls_last=false)".into(), vec![a])
+    );
+    assert_ne!(
+        sort_argument(a, &descending),
+        sort_argument(a, &Default::default())
+    );
+    let options = SortMultipleOptions::default()
+        .with_order_descending(true)
+        .with_maintain_order(true);
+    assert_eq!(
+        apply_parts(sort_by_argument(a, &[b, c], &options)),
+        (
+            "sort_by(descending=[true, true], nulls_last=false, maintain_order=true)".into(),
+            vec![a, b, c]
+        )
+    );
+    assert_eq!(
+        apply_parts(gather_argument(a, b, true)),
+        ("gather(returns_scalar=true)".into(), vec![a, b])
+    );
+    assert_eq!(
+        apply_parts(slice_argument(a, b, c)),
+        ("slice".into(), vec![a, b, c])
+    );
+    assert_eq!(
+        apply_parts(filter_argument(a, b)),
+        ("filter".into(), vec![a, b])
+    );
+    assert_eq!(
+        apply_parts(window_argument(a, &[b, c], WindowMapping::Explode)),
+        ("window(mapping=explode)".into(), vec![a, b, c])
+    );
+    #[cfg(feature = "dynamic_group_by")]
+    {
+        use polars_time::{ClosedWindow, Duration};
+
+        let options = RollingGroupOptions {
+            index_column: "t".into(),
+            period: Duration::parse("2i"),
+            offset: Duration::parse("-2i"),
+            closed_window: ClosedWindow::Right,
+            check_sorted: true,
+        };
+        assert_eq!(
+            apply_parts(rolling_argument(a, &options)),
+            (
+                "rolling(index_column=t, period=2i, offset=-2i, closed=right)".into(),
+                vec![a]
+            )
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_policy_check_reifies_shaping_expressions() -> PolarsResult<()> {
+    let df = df![
+        "a" => [2, 1, 2],
+        "b" => [1, 2, 3],
+    ]?;
+    let policy = DataFramePolicy::Bin(Arc::from(sum_only_policy(&["a", "b"], "b", 3).to_bytes()));
+    let q = |exprs: &[Expr]| {
+        LazyFrame::from_df_with_policy(df.clone(), policy.clone())
+            .select(exprs)
+            .set_ctx_id(open_monitor_context())
+            .set_policy_checking(true)
+            .collect()
+    };
+
+    // The window and the sort are evaluated and reified in the opened context.
+    let out = q(&[
+        col("a").sort(Default::default()).alias("sorted"),
+        col("b").sum().over([col("a")]),
+    ])?;
+    assert_eq!(
+        out,
+        df![
+            "sorted" => [1, 2, 2],
+            "b" => [4, 2, 4],
+        ]?
+    );
+
+    // Sorting does not sum `b` up.
+    assert_policy_violation(q(&[col("b").sort(Default::default())]), "finalize");
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_policy_check_semi_anti_join_filters_left() -> PolarsResult<()> {
+    let df = df![
+        "a" => [2, 1, 2],
+        "b" => [1, 2, 3],
+    ]?;
+    let other = df![
+        "a" => [2],
+    ]?;
+    let policy = DataFramePolicy::Bin(Arc::from(sum_only_policy(&["a", "b"], "b", 3).to_bytes()));
+    let q = |how: JoinType, exprs: &[Expr]| {
+        LazyFrame::from_df_with_policy(df.clone(), policy.clone())
+            .join(
+                other.clone().lazy(),
+                [col("a")],
+                [col("a")],
+                JoinArgs::new(how),
+            )
+            .select(exprs)
+            .set_ctx_id(open_monitor_context())
+            .set_policy_checking(true)
+            .collect()
+    };
+
+    // The joins are reported as filters of the left frame and keep its policies.
+    let out = q(JoinType::Semi, &[col("a"), col("b").sum()])?;
+    assert_eq!(
+        out,
+        df![
+            "a" => [2, 2],
+            "b" => [4, 4],
+        ]?
+    );
+    let out = q(JoinType::Anti, &[col("a"), col("b").sum()])?;
+    assert_eq!(
+        out,
+        df![
+            "a" => [1],
+            "b" => [2],
+        ]?
+    );
+
+    assert_policy_violation(q(JoinType::Semi, &[col("b")]), "finalize");
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_policy_check_asof_join_without_match() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 2, 3],
+    ]?;
+    let policy = DataFramePolicy::Bin(Arc::from(sum_only_policy(&["a", "b"], "b", 3).to_bytes()));
+    let q = |other: DataFrame| {
+        LazyFrame::from_df_with_policy(df.clone(), policy.clone())
+            .join(
+                other.lazy(),
+                [col("a").set_sorted_flag(polars_core::series::IsSorted::Ascending)],
+                [col("a").set_sorted_flag(polars_core::series::IsSorted::Ascending)],
+                JoinArgs::new(JoinType::AsOf(Default::default())),
+            )
+            .select([col("b").sum()])
+            .set_ctx_id(open_monitor_context())
+            .set_policy_checking(true)
+            .collect()
+    };
+
+    let out = q(df!["a" => [1], "c" => [0]]?)?;
+    assert_eq!(out, df!["b" => [6]]?);
+
+    // The first row has no match, which the monitor cannot track.
+    let res = q(df!["a" => [2], "c" => [0]]?);
+    assert!(matches!(res, Err(PolarsError::InvalidOperation(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_policy_check_refuses_untracked_nodes() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    ]?;
+
+    // Explode duplicates rows, which cannot be reported to the monitor yet.
+    let q = df
+        .clone()
+        .lazy()
+        .select([col("a").implode()])
+        .explode([col("a")])
+        .set_policy_checking(true);
+    assert!(matches!(q.collect(), Err(PolarsError::InvalidOperation(_))));
+
+    let q = df
+        .clone()
+        .lazy()
+        .with_context([df.lazy()])
+        .set_policy_checking(true);
+    assert!(matches!(q.collect(), Err(PolarsError::InvalidOperation(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_policy_check_registers_dataframe_policy() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+    let policy = DataFramePolicy::File(Arc::new("does-not-exist.policy".into()));
+
+    // The policy is read when the frame is scanned.
+    for streaming in [false, true] {
+        let q = LazyFrame::from_df_with_policy(df.clone(), policy.clone())
+            .filter(col("a").gt(lit(1)))
+            .with_streaming(streaming)
+            .set_policy_checking(true);
+        assert!(matches!(q.collect(), Err(PolarsError::IO { .. })));
+    }
+
+    // Without policy checking the policy is ignored.
+    let out = df.with_policy(policy).lazy().collect()?;
+    assert_eq!(out.height(), 3);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "streaming")]
+fn test_policy_check_registers_binary_and_file_policy() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+    let bytes = sum_only_policy(&["a"], "a", 3).to_bytes();
+    let path = std::env::temp_dir().join("polars_dataframe_policy.policy");
+    std::fs::write(&path, &bytes)?;
+
+    for policy in [
+        DataFramePolicy::Bin(Arc::from(bytes)),
+        DataFramePolicy::File(Arc::new(path.clone())),
+    ] {
+        // The policy is kept on the frame and registered by both engines.
+        let with_policy = df.clone().with_policy(policy.clone());
+        assert_eq!(with_policy.get_policy(), Some(&policy));
+        for streaming in [false, true] {
+            let q = |a: Expr| {
+                with_policy
+                    .clone()
+                    .lazy()
+                    .filter(col("a").gt(lit(1)))
+                    .select([a])
+                    .with_streaming(streaming)
+                    .set_ctx_id(open_monitor_context())
+                    .set_policy_checking(true)
+            };
+            let out = q(col("a").sum()).collect()?;
+            assert_eq!(out, df![ "a" => [5] ]?);
+            assert_policy_violation(q(col("a")).collect(), "finalize");
+        }
+    }
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_policy_monitor_errors() {
+    use picachv::PicachvError;
+
+    // Only a violated policy is a policy violation.
+    let err = PolarsError::from(PicachvError::InvalidOperation("unknown context".into()));
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    let err = PolarsError::from(PicachvError::ComputeError("corrupted dataframe".into()));
+    assert!(matches!(err, PolarsError::ComputeError(_)));
+    let err = PolarsError::from(PicachvError::PrivacyError("must be aggregated".into()))
+        .with_policy_context("select", Some("a"));
+    match &err {
+        PolarsError::PolicyViolation(violation) => {
+            assert_eq!(violation.clause, "must be aggregated");
+            assert_eq!(violation.operator.as_deref(), Some("select"));
+            assert_eq!(violation.column.as_deref(), Some("a"));
+        },
+        err => panic!("expected a policy violation, got {err:?}"),
+    }
+    assert_eq!(
+        err.to_string(),
+        "policy violation: must be aggregated (in `select` on column `a`)"
+    );
+
+    // The innermost operator that was refused is kept.
+    let err = err.with_policy_context("finalize", None);
+    assert!(err.to_string().ends_with("(in `select` on column `a`)"));
+}
+
+#[test]
+fn test_policy_check_profile_and_concurrent() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+    let policy = DataFramePolicy::File(Arc::new("does-not-exist.policy".into()));
+    let q = LazyFrame::from_df_with_policy(df.clone(), policy)
+        .filter(col("a").gt(lit(1)))
+        .set_policy_checking(true);
+
+    // Profiled and background execution read the policy as well.
+    assert!(matches!(q.clone().profile(), Err(PolarsError::IO { .. })));
+    let res = q.collect_concurrently()?.fetch_blocking();
+    assert!(matches!(res, Err(PolarsError::IO { .. })));
+
+    // The time spent in the monitor is profiled separately.
+    let (out, profile) = df
+        .lazy()
+        .sort(["a"], Default::default())
+        .set_policy_checking(true)
+        .profile()?;
+    assert_eq!(out.height(), 3);
+    let nodes = profile.column("node")?.str()?;
+    for node in ["monitor(scan)", "monitor(transform)"] {
+        assert!(nodes.into_iter().any(|n| n == Some(node)));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_metrics() -> PolarsResult<()> {
+    let df = df![
+        "a" => [3, 1, 2],
+    ]?;
+    let q = df.lazy().sort(["a"], Default::default());
+
+    let (out, metrics) = q.clone().collect_with_metrics()?;
+    assert!(out.equals(&q.clone().collect()?));
+    assert!(metrics.lineage.is_empty());
+    assert_eq!(metrics.monitor_time, std::time::Duration::ZERO);
+
+    let (_, metrics) = q.set_policy_checking(true).collect_with_metrics()?;
+    // The scan and the sort are reported.
+    assert_eq!(metrics.lineage.len(), 2);
+    let nodes = metrics.nodes.column("node")?.str()?;
+    assert!(nodes.into_iter().any(|n| n == Some("monitor(finalize)")));
+    assert!(metrics.monitor_time <= metrics.elapsed);
+
+    Ok(())
+}
+
+#[test]
+fn test_explain_policy() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+    let policy = DataFramePolicy::File(Arc::new("a.policy".into()));
+
+    let q = LazyFrame::from_df_with_policy(df.clone(), policy)
+        .sort(["a"], Default::default())
+        .with_context([df.lazy()]);
+    let explained = q.explain_policy()?;
+    let lines = explained.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("skipped optimizations:") && lines[0].contains("slice_pushdown"));
+    assert_eq!(
+        lines[2..],
+        [
+            "ext_context: refused, 'with_context' cannot be tracked",
+            "  df: GetData, policy: none",
+            "  sort: Transform (reorder)",
+            "    df: GetData, policy: a.policy",
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_policy_check_refuses_scan_without_policy() -> PolarsResult<()> {
+    let q = scan_foods_csv().filter(col("calories").gt(lit(100)));
+
+    let explained = q.explain_policy()?;
+    let scan = explained.lines().last().unwrap();
+    assert!(scan.ends_with("refused, a scan without a policy cannot be tracked"));
+
+    let err = q.set_policy_checking(true).collect().unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    assert!(err.to_string().contains("a scan without a policy"));
+
+    Ok(())
+}
+
+#[test]
+fn test_policy_check_builds_all_aggregations() -> PolarsResult<()> {
+    use crate::physical_plan::expressions::agg_argument;
+
+    let schema: SchemaRef = Arc::new(Schema::from_iter([Field::new("a", DataType::Float64)]));
+    let exprs = [
+        col("a").median(),
+        col("a").n_unique(),
+        col("a").quantile(lit(0.5), QuantileInterpolOptions::Nearest),
+        col("a").std(1),
+        col("a").var(1),
+        col("a").first(),
+        col("a").last(),
+        col("a").implode(),
+        col("a").nan_max(),
+        col("a").count(),
+    ];
+
+    // Aggregations are known to the monitor in both contexts.
+    for expr in exprs {
+        for ctxt in [Context::Aggregation, Context::Default] {
+            let phys_expr = create_policy_checked_expr(expr.clone(), ctxt, &schema)?;
+            assert!(!phys_expr.get_uuid().is_nil());
+        }
+    }
+
+    let a = uuid::Uuid::from_u128(1);
+    let method = |agg_type| match agg_argument(a, agg_type).argument {
+        Some(picachv::expr_argument::Argument::Agg(agg)) => {
+            assert_eq!(agg.input_uuid, a.to_bytes_le());
+            agg.method
+        },
+        arg => panic!("expected an aggregation encoding, got {arg:?}"),
+    };
+    for (agg_type, expected) in [
+        (GroupByMethod::NanMin, picachv::GroupByMethod::Min),
+        (GroupByMethod::NanMax, picachv::GroupByMethod::Max),
+        (GroupByMethod::Median, picachv::GroupByMethod::Median),
+        (
+            GroupByMethod::Quantile(0.25, QuantileInterpolOptions::Linear),
+            picachv::GroupByMethod::Quantile,
+        ),
+        (GroupByMethod::Std(1), picachv::GroupByMethod::Std),
+        (
+            GroupByMethod::Count {
+                include_nulls: false,
+            },
+            picachv::GroupByMethod::Len,
+        ),
+        (GroupByMethod::Implode, picachv::GroupByMethod::Implode),
+    ] {
+        assert_eq!(method(agg_type), expected as i32);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_policy_check_builds_all_binary_operators() -> PolarsResult<()> {
+    use crate::physical_plan::expressions::{binary_argument, op_to_binop};
+
+    let schema: SchemaRef = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int32),
+        Field::new("b", DataType::Boolean),
+    ]));
+    let exprs = [
+        col("a") % lit(10),
+        col("a").floor_div(lit(3)),
+        col("a") / lit(3),
+        col("a").eq_missing(lit(1)),
+        col("a").neq_missing(lit(1)),
+        col("b").xor(lit(true)),
+        col("b").logical_and(lit(true)),
+        col("b").logical_or(lit(false)),
+    ];
+
+    for expr in exprs {
+        let phys_expr = create_policy_checked_expr(expr, Context::Default, &schema)?;
+        assert!(!phys_expr.get_uuid().is_nil());
+    }
+
+    let (a, b) = (uuid::Uuid::from_u128(1), uuid::Uuid::from_u128(2));
+    for (op, name) in [
+        (Operator::EqValidity, "eq_missing"),
+        (Operator::NotEqValidity, "ne_missing"),
+        (Operator::TrueDivide, "truediv"),
+        (Operator::FloorDivide, "floordiv"),
+        (Operator::Modulus, "mod"),
+        (Operator::Xor, "xor"),
+    ] {
+        assert_eq!(
+            apply_parts(binary_argument(a, op, b)),
+            (name.into(), vec![a, b])
+        );
+    }
+    assert_eq!(
+        binary_argument(a, Operator::Divide, b),
+        picachv::ExprArgument {
+            argument: Some(picachv::expr_argument::Argument::Binary(
+                picachv::BinaryExpr {
+                    left_uuid: a.to_bytes_le().to_vec(),
+                    op: op_to_binop(Operator::Divide),
+                    right_uuid: b.to_bytes_le().to_vec(),
+                }
+            )),
+        }
+    );
+    assert_ne!(
+        binary_argument(a, Operator::Eq, b),
+        binary_argument(a, Operator::EqValidity, b)
+    );
+
+    Ok(())
+}
@@ -421,3 +421,57 @@ fn test_streaming_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_streaming_policy_check() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 1, 2, 3],
+        "b" => [1, 2, 3, 4, 5],
+    ]?;
+    let other = df![
+        "a" => [1, 2],
+        "c" => [10, 20],
+    ]?;
+    let policy = DataFramePolicy::Bin(Arc::from(sum_only_policy(&["a", "b"], "b", 5).to_bytes()));
+
+    // The source, filter and projection are streamed, the join and the group-by
+    // break the pipeline and are reported by the default engine.
+    let q = |agg: Expr| {
+        LazyFrame::from_df_with_policy(df.clone(), policy.clone())
+            .filter(col("b").gt(lit(1)))
+            .select([col("a"), (col("b") * lit(2)).alias("b")])
+            .join(
+                other.clone().lazy(),
+                [col("a")],
+                [col("a")],
+                JoinArgs::new(JoinType::Inner),
+            )
+            .group_by([col("a")])
+            .agg([agg, col("c").sum()])
+            .set_policy_checking(true)
+    };
+    assert!(has_pipeline(q(col("b").sum()).with_streaming(true)));
+
+    // `b` may only leave the query summed up.
+    let out = q(col("b").sum())
+        .with_streaming(true)
+        .set_ctx_id(open_monitor_context())
+        .collect()?
+        .sort(["a"], Default::default())?;
+    let expected = q(col("b").sum())
+        .set_ctx_id(open_monitor_context())
+        .collect()?
+        .sort(["a"], Default::default())?;
+    assert!(out.equals(&expected));
+    assert_eq!(out.column("b")?.i32()?.get(1), Some(12));
+
+    for streaming in [false, true] {
+        let res = q(col("b").max())
+            .with_streaming(streaming)
+            .set_ctx_id(open_monitor_context())
+            .collect();
+        assert_policy_violation(res, "finalize");
+    }
+
+    Ok(())
+}
@@ -67,6 +67,9 @@ pub enum JoinType {
     #[cfg(feature = "asof_join")]
     AsOf(AsOfOptions),
     Cross,
+    /// Join the rows for which the keys satisfy one or two inequalities.
+    #[cfg(feature = "range_join")]
+    Range(RangeJoinOptions),
     #[cfg(feature = "semi_anti_join")]
     Semi,
     #[cfg(feature = "semi_anti_join")]
@@ -80,6 +83,8 @@ impl JoinType {
             // Merges them if they are equal
             #[cfg(feature = "asof_join")]
             Self::AsOf(_) => false,
+            #[cfg(feature = "range_join")]
+            Self::Range(_) => false,
             _ => true,
         }
     }
@@ -101,6 +106,8 @@ impl Display for JoinType {
             #[cfg(feature = "asof_join")]
             AsOf(_) => "ASOF",
             Cross => "CROSS",
+            #[cfg(feature = "range_join")]
+            Range(_) => "RANGE",
             #[cfg(feature = "semi_anti_join")]
             Semi => "SEMI",
             #[cfg(feature = "semi_anti_join")]
@@ -593,10 +593,14 @@ pub trait AsofJoinBy: IntoDf {
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<&str>,
         slice: Option<(i64, usize)>,
+        ti: &mut JoinInformation,
     ) -> PolarsResult<DataFrame> {
         let (self_sliced_slot, other_sliced_slot); // Keeps temporaries alive.
         let (self_df, other_df);
+        let (mut left_offset, mut right_offset) = (0, 0);
         if let Some((offset, len)) = slice {
+            left_offset = slice_offsets(offset, len, self.to_df().height()).0;
+            right_offset = slice_offsets(offset, len, other.height()).0;
             self_sliced_slot = self.to_df().slice(offset, len);
             other_sliced_slot = other.slice(offset, len);
             self_df = &self_sliced_slot;
@@ -656,12 +660,14 @@ pub trait AsofJoinBy: IntoDf {
         let proj_other_df = unsafe { DataFrame::new_no_checks(cols) };
 
         let left = self_df.clone();
+        let right_join_tuples = IdxCa::with_chunk("", right_join_tuples);
+        join_columns_info(ti, self_df, other_df, &drop_these);
+        asof_join_rows_info(ti, left_offset, &right_join_tuples, right_offset);
 
         // SAFETY: join tuples are in bounds.
-        let right_df =
-            unsafe { proj_other_df.take_unchecked(&IdxCa::with_chunk("", right_join_tuples)) };
+        let right_df = unsafe { proj_other_df.take_unchecked(&right_join_tuples) };
 
-        _finish_join(left, right_df, suffix, &mut Default::default())
+        _finish_join(left, right_df, suffix, ti)
     }
 
     /// This is similar to a left-join except that we match on nearest key
@@ -687,7 +693,16 @@ pub trait AsofJoinBy: IntoDf {
         let left_by = left_by.into_iter().map(|s| s.as_ref().into()).collect();
         let right_by = right_by.into_iter().map(|s| s.as_ref().into()).collect();
         self_df._join_asof_by(
-            other, left_on, right_on, left_by, right_by, strategy, tolerance, None, None,
+            other,
+            left_on,
+            right_on,
+            left_by,
+            right_by,
+            strategy,
+            tolerance,
+            None,
+            None,
+            &mut Default::default(),
         )
     }
 }
@@ -698,6 +713,66 @@ impl AsofJoinBy for DataFrame {}
 mod test {
     use super::*;
 
+    #[test]
+    fn test_asof_join_information() -> PolarsResult<()> {
+        let a = df![
+            "a" => [-1, 2, 3, 4],
+            "b" => ["a", "b", "c", "d"]
+        ]?;
+        let b = df![
+            "a" => [1, 2, 3],
+            "b" => ["a", "b", "c"],
+            "right_vals" => [1, 2, 3]
+        ]?;
+        // Set the sorted flags.
+        let a = a.sort(["a"], Default::default())?;
+        let b = b.sort(["a"], Default::default())?;
+
+        let mut ti = JoinInformation::default();
+        a._join_asof_by(
+            &b,
+            "a",
+            "a",
+            vec!["b".into()],
+            vec!["b".into()],
+            AsofStrategy::Backward,
+            None,
+            None,
+            Some((1, 3)),
+            &mut ti,
+        )?;
+        let rows = ti
+            .row_join_info
+            .iter()
+            .map(|r| (r.left_row, r.right_row))
+            .collect::<Vec<_>>();
+        // The last row has no match and is not recorded.
+        assert_eq!(rows, [(1, 1), (2, 2)]);
+        assert_eq!(ti.left_columns, [0, 1]);
+        assert_eq!(ti.right_columns, [2]);
+
+        let mut ti = JoinInformation::default();
+        a._join_asof(
+            &b,
+            "a",
+            "a",
+            AsofStrategy::Backward,
+            None,
+            None,
+            None,
+            &mut ti,
+        )?;
+        let rows = ti
+            .row_join_info
+            .iter()
+            .map(|r| (r.left_row, r.right_row))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, [(1, 1), (2, 2), (3, 2)]);
+        assert_eq!(ti.right_columns, [1, 2]);
+        assert_eq!(ti.renaming_info.len(), 1);
+        Ok(())
+    }
+
     #[test]
     fn test_asof_by() -> PolarsResult<()> {
         let a = df![
@@ -4,15 +4,16 @@ use std::borrow::Cow;
 
 use default::*;
 pub use groups::AsofJoinBy;
+use picachv::JoinInformation;
 use polars_core::prelude::*;
-use polars_core::utils::ensure_sorted_arg;
+use polars_core::utils::{ensure_sorted_arg, slice_offsets};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
 
 #[cfg(feature = "dtype-categorical")]
 use super::_check_categorical_src;
-use super::{_finish_join, build_tables, prepare_bytes};
+use super::{_finish_join, asof_join_rows_info, build_tables, join_columns_info, prepare_bytes};
 use crate::frame::IntoDf;
 
 trait AsofJoinState<T>: Default {
@@ -215,6 +216,7 @@ pub trait AsofJoin: IntoDf {
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
+        ti: &mut JoinInformation,
     ) -> PolarsResult<DataFrame> {
         let self_df = self.to_df();
         let left_key = self_df.column(left_on)?;
@@ -271,22 +273,32 @@ pub trait AsofJoin: IntoDf {
         }?;
 
         // Drop right join column.
-        let other = if left_on == right_on {
+        let drop_right_on = left_on == right_on;
+        let dropped = if drop_right_on {
+            vec![right_on]
+        } else {
+            vec![]
+        };
+        join_columns_info(ti, self_df, other, &dropped);
+        let other = if drop_right_on {
             Cow::Owned(other.drop(right_on)?)
         } else {
             Cow::Borrowed(other)
         };
 
         let mut left = self_df.clone();
+        let mut left_offset = 0;
         if let Some((offset, len)) = slice {
+            left_offset = slice_offsets(offset, len, left.height()).0;
             left = left.slice(offset, len);
             take_idx = take_idx.slice(offset, len);
         }
+        asof_join_rows_info(ti, left_offset, &take_idx, 0);
 
         // SAFETY: join tuples are in bounds.
         let right_df = unsafe { other.take_unchecked(&take_idx) };
 
-        _finish_join(left, right_df, suffix.as_deref(), &mut Default::default())
+        _finish_join(left, right_df, suffix.as_deref(), ti)
     }
 
     /// This is similar to a left-join except that we match on nearest key rather than equal keys.
@@ -300,7 +312,16 @@ pub trait AsofJoin: IntoDf {
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<String>,
     ) -> PolarsResult<DataFrame> {
-        self._join_asof(other, left_on, right_on, strategy, tolerance, suffix, None)
+        self._join_asof(
+            other,
+            left_on,
+            right_on,
+            strategy,
+            tolerance,
+            suffix,
+            None,
+            &mut Default::default(),
+        )
     }
 }
 
@@ -51,21 +51,6 @@ pub trait CrossJoin: IntoDf {
         let n_rows_left = df_self.height() as IdxSize;
         let n_rows_right = other.height() as IdxSize;
 
-        // if slice.is_none() {
-        ti.row_join_info = (0..n_rows_left)
-            .into_par_iter()
-            .map(|x| {
-                (0..n_rows_right)
-                    .into_par_iter()
-                    .map(move |y| RowJoinInformation {
-                        left_row: x as u64,
-                        right_row: y as u64,
-                    })
-            })
-            .flatten()
-            .collect();
-        // }
-
         let Some(total_rows) = n_rows_left.checked_mul(n_rows_right) else {
             polars_bail!(
                 ComputeError: "cross joins would produce more rows than fits into 2^32; \
@@ -73,9 +58,23 @@ pub trait CrossJoin: IntoDf {
             );
         };
         if n_rows_left == 0 || n_rows_right == 0 {
+            ti.row_join_info.clear();
             return Ok((df_self.clear(), other.clear()));
         }
 
+        // Output row `i` combines left row `i / n_rows_right` with right row `i % n_rows_right`.
+        let (offset, len) = match slice {
+            Some((offset, len)) => slice_offsets(offset, len, total_rows as usize),
+            None => (0, total_rows as usize),
+        };
+        ti.row_join_info = (offset..offset + len)
+            .into_par_iter()
+            .map(|i| RowJoinInformation {
+                left_row: (i / n_rows_right as usize) as u64,
+                right_row: (i % n_rows_right as usize) as u64,
+            })
+            .collect();
+
         // the left side has the Nth row combined with every row from right.
         // So let's say we have the following no. of rows
         // left: 3
@@ -143,20 +142,7 @@ pub trait CrossJoin: IntoDf {
         slice: Option<(i64, usize)>,
         ti: &mut JoinInformation,
     ) -> PolarsResult<DataFrame> {
-        // we keep all left columns.
-        ti.left_columns = self
-            .to_df()
-            .get_column_names()
-            .iter()
-            .enumerate()
-            .map(|(i, _)| i as _)
-            .collect();
-        ti.right_columns = other
-            .get_column_names()
-            .iter()
-            .enumerate()
-            .map(|(i, _)| i as _)
-            .collect();
+        join_columns_info(ti, self.to_df(), other, &[]);
 
         let (l_df, r_df) = self.cross_join_dfs(other, slice, true, ti)?;
 
@@ -165,3 +151,33 @@ pub trait CrossJoin: IntoDf {
 }
 
 impl CrossJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cross_join_information() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1, 2, 3],
+        ]?;
+        let b = df![
+            "a" => [1, 2],
+            "b" => [3, 4],
+        ]?;
+
+        let mut ti = JoinInformation::default();
+        let out = a.cross_join(&b, None, Some((1, 3)), &mut ti)?;
+        assert_eq!(out.height(), 3);
+        let rows = ti
+            .row_join_info
+            .iter()
+            .map(|r| (r.left_row, r.right_row))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, [(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(ti.left_columns, [0]);
+        assert_eq!(ti.right_columns, [0, 1]);
+        assert_eq!(ti.renaming_info.len(), 1);
+        Ok(())
+    }
+}
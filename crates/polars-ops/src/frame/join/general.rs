@@ -11,6 +11,48 @@ fn get_suffix(suffix: Option<&str>) -> &str {
     suffix.unwrap_or("_right")
 }
 
+/// Record in `ti` that the output of a join keeps every column of `left` and the columns of
+/// `right` that are not in `drop`.
+pub(super) fn join_columns_info(
+    ti: &mut JoinInformation,
+    left: &DataFrame,
+    right: &DataFrame,
+    drop: &[&str],
+) {
+    ti.left_columns = (0..left.width() as u64).collect();
+    ti.right_columns = right
+        .get_columns()
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| !drop.contains(&s.name()))
+        .map(|(i, _)| i as u64)
+        .collect();
+}
+
+/// Record in `ti` that output row `i` joins row `left_offset + i` of the left [`DataFrame`] with
+/// row `right_offset + right_idx[i]` of the right [`DataFrame`].
+///
+/// The monitor has no encoding for a row without a match, so the rows with a null index are not
+/// recorded and `ti` covers fewer rows than the output.
+#[cfg(feature = "asof_join")]
+pub(super) fn asof_join_rows_info(
+    ti: &mut JoinInformation,
+    left_offset: usize,
+    right_idx: &IdxCa,
+    right_offset: usize,
+) {
+    ti.row_join_info = right_idx
+        .iter()
+        .enumerate()
+        .filter_map(|(i, r)| {
+            r.map(|r| RowJoinInformation {
+                left_row: (left_offset + i) as u64,
+                right_row: (right_offset + r as usize) as u64,
+            })
+        })
+        .collect();
+}
+
 /// Utility method to finish a join.
 #[doc(hidden)]
 pub fn _finish_join(
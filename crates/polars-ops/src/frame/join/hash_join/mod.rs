@@ -239,7 +239,6 @@ pub trait JoinDispatch: IntoDf {
         slice: Option<(i64, usize)>,
         anti: bool,
         join_nulls: bool,
-        ti: &mut picachv::JoinInformation,
     ) -> PolarsResult<DataFrame> {
         let ca_self = self.to_df();
         #[cfg(feature = "dtype-categorical")]
@@ -247,20 +246,6 @@ pub trait JoinDispatch: IntoDf {
 
         let idx = s_left.hash_join_semi_anti(s_right, anti, join_nulls);
 
-        for l in idx.iter() {
-            ti.row_join_info.push(RowJoinInformation {
-                left_row: *l as u64,
-                right_row: 0,
-            });
-        }
-
-        ti.left_columns = ca_self
-            .get_column_names()
-            .iter()
-            .enumerate()
-            .map(|(i, _)| i as u64)
-            .collect();
-
         // SAFETY:
         // indices are in bounds
         Ok(unsafe { ca_self._finish_anti_semi_join(&idx, slice) })
@@ -8,6 +8,8 @@ mod general;
 mod hash_join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+#[cfg(feature = "range_join")]
+mod range_join;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -23,8 +25,11 @@ pub(crate) use checks::*;
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
+#[cfg(feature = "asof_join")]
+use general::asof_join_rows_info;
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
+use general::join_columns_info;
 pub use general::{_coalesce_outer_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
@@ -43,6 +48,8 @@ use polars_core::utils::slice_offsets;
 use polars_core::utils::slice_slice;
 use polars_core::POOL;
 use polars_utils::hashing::BytesHash;
+#[cfg(feature = "range_join")]
+pub use range_join::{InequalityOperator, RangeJoin, RangeJoinOptions};
 use rayon::prelude::*;
 
 use super::IntoDf;
@@ -138,6 +145,20 @@ pub trait DataFrameJoinOps: IntoDf {
             return left_df.cross_join(other, args.suffix.as_deref(), args.slice, ti);
         }
 
+        // The keys of a range join are compared, not hashed, and may differ in type.
+        #[cfg(feature = "range_join")]
+        if let JoinType::Range(options) = &args.how {
+            return left_df._range_join(
+                other,
+                &selected_left,
+                &selected_right,
+                options,
+                args.suffix.as_deref(),
+                args.slice,
+                ti,
+            );
+        }
+
         #[cfg(feature = "chunked_ids")]
         {
             // a left join create chunked-ids
@@ -235,7 +256,6 @@ pub trait DataFrameJoinOps: IntoDf {
                     args.slice,
                     true,
                     args.join_nulls,
-                    ti,
                 ),
                 #[cfg(feature = "semi_anti_join")]
                 JoinType::Semi => left_df._semi_anti_join_from_series(
@@ -244,7 +264,6 @@ pub trait DataFrameJoinOps: IntoDf {
                     args.slice,
                     false,
                     args.join_nulls,
-                    ti,
                 ),
                 #[cfg(feature = "asof_join")]
                 JoinType::AsOf(options) => {
@@ -262,6 +281,7 @@ pub trait DataFrameJoinOps: IntoDf {
                             options.tolerance,
                             args.suffix.as_deref(),
                             args.slice,
+                            ti,
                         ),
                         (None, None) => left_df._join_asof(
                             other,
@@ -271,6 +291,7 @@ pub trait DataFrameJoinOps: IntoDf {
                             options.tolerance,
                             args.suffix,
                             args.slice,
+                            ti,
                         ),
                         _ => {
                             panic!("expected by arguments on both sides")
@@ -280,6 +301,10 @@ pub trait DataFrameJoinOps: IntoDf {
                 JoinType::Cross => {
                     unreachable!()
                 },
+                #[cfg(feature = "range_join")]
+                JoinType::Range(_) => {
+                    unreachable!()
+                },
             };
         }
 
@@ -296,6 +321,10 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Cross => {
                 unreachable!()
             },
+            #[cfg(feature = "range_join")]
+            JoinType::Range(_) => {
+                unreachable!()
+            },
             JoinType::Outer { coalesce } => {
                 let names_left = selected_left.iter().map(|s| s.name()).collect::<Vec<_>>();
                 args.how = JoinType::Outer { coalesce: false };
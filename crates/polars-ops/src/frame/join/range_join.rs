@@ -0,0 +1,410 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use picachv::{JoinInformation, RowJoinInformation};
+use polars_core::prelude::*;
+use polars_core::utils::{slice_slice, try_get_supertype};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{_finish_join, join_columns_info, JoinDispatch};
+use crate::frame::IntoDf;
+
+/// The comparison of a left key with a right key in a range join.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InequalityOperator {
+    /// `left < right`
+    Lt,
+    /// `left <= right`
+    LtEq,
+    /// `left > right`
+    Gt,
+    /// `left >= right`
+    GtEq,
+}
+
+impl InequalityOperator {
+    /// The operator that gives the same result if the operands are swapped.
+    pub fn flip(self) -> Self {
+        use InequalityOperator::*;
+        match self {
+            Lt => Gt,
+            LtEq => GtEq,
+            Gt => Lt,
+            GtEq => LtEq,
+        }
+    }
+
+    #[inline]
+    fn matches<T: PartialOrd>(self, left: T, right: T) -> bool {
+        use InequalityOperator::*;
+        match self {
+            Lt => left < right,
+            LtEq => left <= right,
+            Gt => left > right,
+            GtEq => left >= right,
+        }
+    }
+}
+
+impl Display for InequalityOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use InequalityOperator::*;
+        let op = match self {
+            Lt => "<",
+            LtEq => "<=",
+            Gt => ">",
+            GtEq => ">=",
+        };
+        write!(f, "{op}")
+    }
+}
+
+/// The conditions of a range join. The first key of the left [`DataFrame`] is compared with
+/// the first key of the right [`DataFrame`] by `operator1`, the second keys by `operator2`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RangeJoinOptions {
+    pub operator1: InequalityOperator,
+    pub operator2: Option<InequalityOperator>,
+}
+
+/// The values of a join key, where null and NaN values, which never match, are `None`.
+fn key_values<T: PolarsNumericType>(s: &Series) -> Vec<Option<T::Native>> {
+    let ca: &ChunkedArray<T> = s.as_ref().as_ref();
+    ca.iter()
+        .map(|v| v.filter(|v| v.partial_cmp(v).is_some()))
+        .collect()
+}
+
+fn cast_keys(left: &Series, right: &Series) -> PolarsResult<(Series, Series)> {
+    let supertype = try_get_supertype(left.dtype(), right.dtype())?;
+    let physical = supertype.to_physical();
+    polars_ensure!(
+        physical.is_numeric(),
+        InvalidOperation: "range join is not supported on keys of type {}", supertype
+    );
+    let cast_to = if physical.is_float() {
+        DataType::Float64
+    } else if physical == DataType::UInt64 {
+        DataType::UInt64
+    } else {
+        DataType::Int64
+    };
+    let cast = |s: &Series| -> PolarsResult<Series> {
+        s.cast(&supertype)?.to_physical_repr().cast(&cast_to)
+    };
+    Ok((cast(left)?, cast(right)?))
+}
+
+type PairFilter = Box<dyn Fn(usize, usize) -> bool + Send + Sync>;
+
+fn pair_filter_impl<T: PolarsNumericType>(
+    left: &Series,
+    right: &Series,
+    op: InequalityOperator,
+) -> PairFilter {
+    let left = key_values::<T>(left);
+    let right = key_values::<T>(right);
+    Box::new(move |l, r| match (left[l], right[r]) {
+        (Some(l), Some(r)) => op.matches(l, r),
+        _ => false,
+    })
+}
+
+/// A check of the second condition of a range join for a pair of rows, on keys that were cast
+/// by [`cast_keys`].
+fn pair_filter(left: &Series, right: &Series, op: InequalityOperator) -> PairFilter {
+    match left.dtype() {
+        DataType::Float64 => pair_filter_impl::<Float64Type>(left, right, op),
+        DataType::UInt64 => pair_filter_impl::<UInt64Type>(left, right, op),
+        _ => pair_filter_impl::<Int64Type>(left, right, op),
+    }
+}
+
+/// The second condition of a range join.
+enum SecondCondition<'a> {
+    /// The second left key is compared with the same right key as the first one, e.g.
+    /// `start <= ts AND ts < end`, so both conditions bound the same sorted right keys.
+    SameRightKey(&'a Series, InequalityOperator),
+    /// The second condition is checked for every pair that matches the first one.
+    Filter(PairFilter),
+}
+
+/// The range of the sorted right keys `r` for which `v op r` holds.
+fn matching_range<T: PartialOrd>(
+    sorted_right: &[(T, IdxSize)],
+    op: InequalityOperator,
+    v: T,
+) -> Range<usize> {
+    match op {
+        InequalityOperator::Lt => {
+            sorted_right.partition_point(|(r, _)| *r <= v)..sorted_right.len()
+        },
+        InequalityOperator::LtEq => {
+            sorted_right.partition_point(|(r, _)| *r < v)..sorted_right.len()
+        },
+        InequalityOperator::Gt => 0..sorted_right.partition_point(|(r, _)| *r < v),
+        InequalityOperator::GtEq => 0..sorted_right.partition_point(|(r, _)| *r <= v),
+    }
+}
+
+/// Find the pairs of rows for which `left op right` holds and that meet the `second`
+/// condition. The right keys are sorted once, after which the matches of every left row form a
+/// contiguous range that is found by binary search. The pairs are returned in the order of the
+/// left rows and then in the order of the right rows.
+///
+/// With `n` left rows, `m` right rows and `k` matching pairs this takes
+/// `O((n + m) log m + k log k)` if the second condition is on the same right key, whose range
+/// is then intersected with the first one. A [`SecondCondition::Filter`] is checked for every
+/// pair that matches the first condition, which is `O(n * m)` if the first condition is not
+/// selective.
+fn range_join_tuples_impl<T: PolarsNumericType>(
+    left: &Series,
+    right: &Series,
+    op: InequalityOperator,
+    second: Option<&SecondCondition>,
+) -> (Vec<IdxSize>, Vec<IdxSize>) {
+    let left = key_values::<T>(left);
+    let mut sorted_right = key_values::<T>(right)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|v| (v, i as IdxSize)))
+        .collect::<Vec<_>>();
+    sorted_right.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let (left2, op2) = match second {
+        Some(SecondCondition::SameRightKey(left2, op2)) => (Some(key_values::<T>(left2)), *op2),
+        _ => (None, op),
+    };
+
+    let mut tuples_left = Vec::new();
+    let mut tuples_right = Vec::new();
+    let mut matches = Vec::new();
+    for (l, v) in left.into_iter().enumerate() {
+        let Some(v) = v else { continue };
+        let mut range = matching_range(&sorted_right, op, v);
+        if let Some(left2) = &left2 {
+            let Some(v2) = left2[l] else { continue };
+            let range2 = matching_range(&sorted_right, op2, v2);
+            let start = range.start.max(range2.start);
+            range = start..range.end.min(range2.end).max(start);
+        }
+        matches.clear();
+        matches.extend(
+            sorted_right[range]
+                .iter()
+                .map(|(_, r)| *r)
+                .filter(|r| match second {
+                    Some(SecondCondition::Filter(f)) => f(l, *r as usize),
+                    _ => true,
+                }),
+        );
+        matches.sort_unstable();
+        tuples_left.resize(tuples_left.len() + matches.len(), l as IdxSize);
+        tuples_right.extend_from_slice(&matches);
+    }
+    (tuples_left, tuples_right)
+}
+
+fn range_join_tuples(
+    selected_left: &[Series],
+    selected_right: &[Series],
+    options: &RangeJoinOptions,
+) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)> {
+    let n_keys = if options.operator2.is_some() { 2 } else { 1 };
+    polars_ensure!(
+        selected_left.len() == n_keys && selected_right.len() == n_keys,
+        ComputeError: "a range join with {} condition(s) expects {} key(s) on both sides, got {} on the left and {} on the right",
+        n_keys, n_keys, selected_left.len(), selected_right.len()
+    );
+
+    let (left, right) = cast_keys(&selected_left[0], &selected_right[0])?;
+    let second_keys = options
+        .operator2
+        .map(|op| {
+            let (left2, right2) = cast_keys(&selected_left[1], &selected_right[1])?;
+            PolarsResult::Ok((left2, right2, op))
+        })
+        .transpose()?;
+    let second = second_keys.as_ref().map(|(left2, right2, op)| {
+        if right2.dtype() == right.dtype() && right2.equals_missing(&right) {
+            SecondCondition::SameRightKey(left2, *op)
+        } else {
+            SecondCondition::Filter(pair_filter(left2, right2, *op))
+        }
+    });
+
+    let op = options.operator1;
+    Ok(match left.dtype() {
+        DataType::Float64 => {
+            range_join_tuples_impl::<Float64Type>(&left, &right, op, second.as_ref())
+        },
+        DataType::UInt64 => {
+            range_join_tuples_impl::<UInt64Type>(&left, &right, op, second.as_ref())
+        },
+        _ => range_join_tuples_impl::<Int64Type>(&left, &right, op, second.as_ref()),
+    })
+}
+
+pub trait RangeJoin: IntoDf {
+    /// Join the rows of two [`DataFrame`]s for which the inequalities of `options` hold between
+    /// the keys. Unlike a cross join followed by a filter, only the matching pairs of rows are
+    /// materialized. All columns of both [`DataFrame`]s are kept.
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn _range_join(
+        &self,
+        other: &DataFrame,
+        selected_left: &[Series],
+        selected_right: &[Series],
+        options: &RangeJoinOptions,
+        suffix: Option<&str>,
+        slice: Option<(i64, usize)>,
+        ti: &mut JoinInformation,
+    ) -> PolarsResult<DataFrame> {
+        let left_df = self.to_df();
+        let (tuples_left, tuples_right) =
+            range_join_tuples(selected_left, selected_right, options)?;
+        let (mut tuples_left, mut tuples_right) = (&*tuples_left, &*tuples_right);
+        if let Some((offset, len)) = slice {
+            tuples_left = slice_slice(tuples_left, offset, len);
+            tuples_right = slice_slice(tuples_right, offset, len);
+        }
+
+        ti.row_join_info = tuples_left
+            .iter()
+            .zip(tuples_right)
+            .map(|(l, r)| RowJoinInformation {
+                left_row: *l as u64,
+                right_row: *r as u64,
+            })
+            .collect();
+        join_columns_info(ti, left_df, other, &[]);
+
+        // SAFETY: the join indices are in bounds and the left ones are sorted.
+        let (df_left, df_right) = unsafe {
+            (
+                left_df._create_left_df_from_slice(tuples_left, false, true),
+                other._take_unchecked_slice(tuples_right, true),
+            )
+        };
+        _finish_join(df_left, df_right, suffix, ti)
+    }
+}
+
+impl RangeJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_range_join() -> PolarsResult<()> {
+        let left = df![
+            "id" => [0, 1, 2],
+            "start" => [1, 4, 10],
+            "end" => [5, 8, 11],
+        ]?;
+        let right = df![
+            "ts" => [Some(6i64), Some(2), None, Some(4), Some(7)],
+        ]?;
+
+        let options = RangeJoinOptions {
+            operator1: InequalityOperator::LtEq,
+            operator2: Some(InequalityOperator::Gt),
+        };
+        let mut ti = JoinInformation::default();
+        let out = left._range_join(
+            &right,
+            &[left.column("start")?.clone(), left.column("end")?.clone()],
+            &[right.column("ts")?.clone(), right.column("ts")?.clone()],
+            &options,
+            None,
+            None,
+            &mut ti,
+        )?;
+        let expected = df![
+            "id" => [0, 0, 1, 1, 1],
+            "start" => [1, 1, 4, 4, 4],
+            "end" => [5, 5, 8, 8, 8],
+            "ts" => [2i64, 4, 6, 4, 7],
+        ]?;
+        assert!(out.equals(&expected));
+        let rows = ti
+            .row_join_info
+            .iter()
+            .map(|r| (r.left_row, r.right_row))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, [(0, 1), (0, 3), (1, 0), (1, 3), (1, 4)]);
+        assert_eq!(ti.right_columns, [0]);
+
+        let options = RangeJoinOptions {
+            operator1: InequalityOperator::Gt,
+            operator2: None,
+        };
+        let out = left._range_join(
+            &right,
+            &[left.column("start")?.clone()],
+            &[right.column("ts")?.clone()],
+            &options,
+            None,
+            Some((1, 2)),
+            &mut ti,
+        )?;
+        assert_eq!(Vec::from(out.column("ts")?.i64()?), [Some(6), Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_join_second_condition() -> PolarsResult<()> {
+        use InequalityOperator::*;
+
+        let start = Series::new("start", [Some(1i64), Some(4), None, Some(10), Some(3)]);
+        let end = Series::new("end", [Some(5i64), Some(2), Some(8), Some(11), Some(9)]);
+        let ts = Series::new(
+            "ts",
+            [Some(6i64), Some(2), None, Some(4), Some(7), Some(10)],
+        );
+        let other = Series::new(
+            "other",
+            [Some(1i64), Some(9), Some(3), None, Some(7), Some(2)],
+        );
+        let values = |s: &Series| Vec::from(s.i64().unwrap());
+
+        // Both the intersected ranges of a shared right key and the checked pairs of a second
+        // right key give the pairs that meet both conditions.
+        for right2 in [&ts, &other] {
+            for operator1 in [Lt, LtEq, Gt, GtEq] {
+                for operator2 in [Lt, LtEq, Gt, GtEq] {
+                    let options = RangeJoinOptions {
+                        operator1,
+                        operator2: Some(operator2),
+                    };
+                    let (left, right) = range_join_tuples(
+                        &[start.clone(), end.clone()],
+                        &[ts.clone(), right2.clone()],
+                        &options,
+                    )?;
+                    let pairs = left.into_iter().zip(right).collect::<Vec<_>>();
+
+                    let matches = |op: InequalityOperator, l: Option<i64>, r: Option<i64>| matches!((l, r), (Some(l), Some(r)) if op.matches(l, r));
+                    let (starts, ends) = (values(&start), values(&end));
+                    let (keys1, keys2) = (values(&ts), values(right2));
+                    let mut expected = vec![];
+                    for l in 0..starts.len() {
+                        for r in 0..keys1.len() {
+                            if matches(operator1, starts[l], keys1[r])
+                                && matches(operator2, ends[l], keys2[r])
+                            {
+                                expected.push((l as IdxSize, r as IdxSize));
+                            }
+                        }
+                    }
+                    assert_eq!(pairs, expected, "{operator1} {operator2} {}", right2.name());
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use picachv::plan_argument::Argument;
+use picachv::{PlanArgument, SelectArgument, TransformInfo};
 use polars_core::error::PolarsResult;
 use polars_core::prelude::polars_err;
 
@@ -27,7 +29,18 @@ impl Operator for FilterOperator {
         })?;
         // the filter is sequential as they are already executed on different threads
         // we don't want to increase contention and data copies
-        let df = chunk.data._filter_seq(mask)?;
+        let mut df = chunk.data._filter_seq(mask)?;
+
+        if context.policy_check() {
+            let pred_bool = mask.iter().map(|b| b.unwrap_or(false)).collect::<Vec<_>>();
+            let plan_arg = PlanArgument {
+                argument: Some(Argument::Select(SelectArgument {
+                    pred_uuid: self.predicate.get_uuid()?.to_bytes_le().to_vec(),
+                })),
+                transform_info: Some(TransformInfo::from_filter(&pred_bool)?),
+            };
+            df.set_uuid(context.execute_epilogue(chunk.data.get_uuid(), plan_arg, "filter")?);
+        }
 
         Ok(OperatorResult::Finished(chunk.with_data(df)))
     }
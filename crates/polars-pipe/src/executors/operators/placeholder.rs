@@ -32,9 +32,9 @@ impl Operator for CallBack {
         lock.as_mut().unwrap().execute(context, chunk)
     }
 
-    fn flush(&mut self) -> PolarsResult<OperatorResult> {
+    fn flush(&mut self, context: &PExecutionContext) -> PolarsResult<OperatorResult> {
         let mut lock = self.inner.try_lock().expect("no-contention");
-        lock.as_mut().unwrap().flush()
+        lock.as_mut().unwrap().flush(context)
     }
 
     fn must_flush(&self) -> bool {
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use picachv::plan_argument::Argument;
+use picachv::{HstackArgument, PlanArgument, ProjectionArgument};
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
@@ -29,12 +31,12 @@ impl Operator for SimpleProjectionOperator {
         _context: &PExecutionContext,
         chunk: &DataChunk,
     ) -> PolarsResult<OperatorResult> {
-        let chunk = chunk.with_data(
-            chunk
-                .data
-                .select_with_schema_unchecked(self.columns.as_ref(), &self.input_schema)?,
-        );
-        Ok(OperatorResult::Finished(chunk))
+        let mut df = chunk
+            .data
+            .select_with_schema_unchecked(self.columns.as_ref(), &self.input_schema)?;
+        // Selecting columns by name is not reported to the monitor, the chunk stays the same.
+        df.set_uuid(chunk.data.get_uuid());
+        Ok(OperatorResult::Finished(chunk.with_data(df)))
     }
     fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
         Box::new(self.clone())
@@ -59,10 +61,7 @@ impl Operator for ProjectionOperator {
         // add temporary cse column to the chunk
         let cse_owned_chunk;
         let chunk = if let Some(hstack) = &mut self.cse_exprs {
-            let OperatorResult::Finished(out) = hstack.execute(context, chunk)? else {
-                unreachable!()
-            };
-            cse_owned_chunk = out;
+            cse_owned_chunk = chunk.with_data(hstack.execute_impl(context, chunk)?);
             &cse_owned_chunk
         } else {
             chunk
@@ -98,8 +97,22 @@ impl Operator for ProjectionOperator {
             }
         }
 
-        let chunk = chunk.with_data(unsafe { DataFrame::new_no_checks(projected) });
-        Ok(OperatorResult::Finished(chunk))
+        let mut df = unsafe { DataFrame::new_no_checks(projected) };
+        if context.policy_check() {
+            let plan_arg = PlanArgument {
+                argument: Some(Argument::Projection(ProjectionArgument {
+                    expressions: self
+                        .exprs
+                        .iter()
+                        .map(|e| Ok(e.get_uuid()?.to_bytes_le().to_vec()))
+                        .collect::<PolarsResult<_>>()?,
+                })),
+                transform_info: None,
+            };
+            df.set_uuid(context.execute_epilogue(chunk.data.get_uuid(), plan_arg, "select")?);
+        }
+
+        Ok(OperatorResult::Finished(chunk.with_data(df)))
     }
     fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
         Box::new(self.clone())
@@ -124,20 +137,19 @@ pub(crate) struct HstackOperator {
     pub(crate) unchecked: bool,
 }
 
-impl Operator for HstackOperator {
-    fn execute(
+impl HstackOperator {
+    /// Add the columns to the chunk without reporting to the monitor. The cse columns of a
+    /// projection are reported as part of that projection.
+    fn execute_impl(
         &mut self,
         context: &PExecutionContext,
         chunk: &DataChunk,
-    ) -> PolarsResult<OperatorResult> {
+    ) -> PolarsResult<DataFrame> {
         // add temporary cse column to the chunk
         let width = chunk.data.width();
         let cse_owned_chunk;
         let chunk = if let Some(hstack) = &mut self.cse_exprs {
-            let OperatorResult::Finished(out) = hstack.execute(context, chunk)? else {
-                unreachable!()
-            };
-            cse_owned_chunk = out;
+            cse_owned_chunk = chunk.with_data(hstack.execute_impl(context, chunk)?);
             &cse_owned_chunk
         } else {
             chunk
@@ -158,9 +170,43 @@ impl Operator for HstackOperator {
         } else {
             df._add_columns(projected, schema)?;
         }
+        Ok(df)
+    }
+}
+
+impl Operator for HstackOperator {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let mut df = self.execute_impl(context, chunk)?;
+
+        if context.policy_check() {
+            let plan_arg = PlanArgument {
+                argument: Some(Argument::Hstack(HstackArgument {
+                    cse: self
+                        .cse_exprs
+                        .iter()
+                        .flat_map(|hstack| hstack.exprs.iter())
+                        .map(|e| Ok(e.get_uuid()?.to_bytes_le().to_vec()))
+                        .collect::<PolarsResult<_>>()?,
+                    expressions: self
+                        .exprs
+                        .iter()
+                        .map(|e| Ok(e.get_uuid()?.to_bytes_le().to_vec()))
+                        .collect::<PolarsResult<_>>()?,
+                })),
+                transform_info: None,
+            };
+            df.set_uuid(context.execute_epilogue(
+                chunk.data.get_uuid(),
+                plan_arg,
+                "with_columns",
+            )?);
+        }
 
-        let chunk = chunk.with_data(df);
-        Ok(OperatorResult::Finished(chunk))
+        Ok(OperatorResult::Finished(chunk.with_data(df)))
     }
     fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
         Box::new(self.clone())
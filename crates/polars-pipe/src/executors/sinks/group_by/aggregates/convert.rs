@@ -2,7 +2,7 @@ use std::any::Any;
 use std::sync::Arc;
 
 use polars_core::datatypes::Field;
-use polars_core::error::PolarsResult;
+use polars_core::error::{polars_bail, PolarsResult};
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{DataType, SchemaRef, Series, IDX_DTYPE};
 use polars_core::schema::Schema;
@@ -13,6 +13,7 @@ use polars_plan::logical_plan::{ArenaExprIter, Context};
 use polars_plan::prelude::{AAggExpr, AExpr};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::IdxSize;
+use uuid::Uuid;
 
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
@@ -45,6 +46,10 @@ impl PhysicalPipedExpr for Len {
     fn expression(&self) -> Expr {
         Expr::Len
     }
+
+    fn get_uuid(&self) -> PolarsResult<Uuid> {
+        polars_bail!(InvalidOperation: "the group length is not registered with the monitor")
+    }
 }
 
 pub fn can_convert_to_hash_agg(
@@ -1,7 +1,7 @@
 use polars_core::config::verbose;
 
 use super::*;
-use crate::executors::sinks::memory::MemTracker;
+use crate::executors::sinks::memory::{MemTracker, TO_DISK_THRESHOLD};
 use crate::pipeline::{morsels_per_sink, FORCE_OOC};
 
 #[derive(Clone)]
@@ -39,9 +39,6 @@ impl Default for OocState {
 // If this is reached we early merge the overflow buckets
 // to free up memory
 const EARLY_MERGE_THRESHOLD: f64 = 0.5;
-// If this is reached we spill to disk and
-// aggregate in a second run
-const TO_DISK_THRESHOLD: f64 = 0.3;
 
 pub(super) enum SpillAction {
     EarlyMerge,
@@ -27,7 +27,7 @@ pub(crate) struct IOThread {
     pub(in crate::executors::sinks) sent: Arc<AtomicUsize>,
     pub(in crate::executors::sinks) total: Arc<AtomicUsize>,
     pub(in crate::executors::sinks) thread_local_count: Arc<AtomicUsize>,
-    schema: SchemaRef,
+    pub(in crate::executors::sinks) schema: SchemaRef,
 }
 
 fn get_lockfile_path(dir: &Path) -> PathBuf {
@@ -14,10 +14,11 @@ use super::*;
 use crate::executors::operators::PlaceHolder;
 use crate::executors::sinks::joins::generic_probe_inner_left::GenericJoinProbe;
 use crate::executors::sinks::joins::generic_probe_outer::GenericOuterJoinProbe;
+use crate::executors::sinks::joins::ooc::{GenericOocJoinProbe, OocState};
 use crate::executors::sinks::utils::{hash_rows, load_vec};
 use crate::executors::sinks::HASHMAP_INIT_SIZE;
 use crate::expressions::PhysicalPipedExpr;
-use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::operators::{DataChunk, FinalizedSink, Operator, PExecutionContext, Sink, SinkResult};
 
 pub(super) type ChunkIdx = IdxSize;
 pub(super) type DfIdx = IdxSize;
@@ -53,6 +54,8 @@ pub struct GenericBuild<K: ExtraPayload> {
     key_names_left: Arc<[SmartString]>,
     key_names_right: Arc<[SmartString]>,
     placeholder: PlaceHolder,
+    // spill the build side if it doesn't fit in memory
+    ooc_state: OocState,
 }
 
 impl<K: ExtraPayload> GenericBuild<K> {
@@ -91,8 +94,31 @@ impl<K: ExtraPayload> GenericBuild<K> {
             key_names_left,
             key_names_right,
             placeholder,
+            ooc_state: OocState::new(),
         }
     }
+
+    /// A new build with the same join settings that is never spilled. This is used to join
+    /// the partitions of a spilled build.
+    pub(super) fn split_in_memory(&self) -> Self {
+        let mut new = Self::new(
+            self.suffix.clone(),
+            self.join_type.clone(),
+            self.swapped,
+            self.join_columns_left.clone(),
+            self.join_columns_right.clone(),
+            self.join_nulls,
+            self.node,
+            self.key_names_left.clone(),
+            self.key_names_right.clone(),
+            // The probe operator owns these builds, so they may not hold on to the
+            // placeholder that owns the probe operator.
+            PlaceHolder::new(),
+        );
+        new.hb = self.hb.clone();
+        new.ooc_state = OocState::in_memory();
+        new
+    }
 }
 
 #[inline]
@@ -151,6 +177,113 @@ impl<K: ExtraPayload> GenericBuild<K> {
             .get_unchecked_release(chunk_idx as usize)
             .value_unchecked(df_idx as usize)
     }
+
+    /// Spill the chunks that are held in memory and clear the hash tables.
+    fn spill_in_memory(&mut self) {
+        let chunks = std::mem::take(&mut self.chunks);
+        let materialized_join_cols = std::mem::take(&mut self.materialized_join_cols);
+        // `self.hashes` may still hold the hashes of the chunk that triggered the spill,
+        // so the spilled chunks are hashed in a buffer of their own
+        let mut hashes = vec![];
+        // an empty chunk has no materialized join columns
+        for (chunk, rows) in chunks
+            .iter()
+            .filter(|chunk| !chunk.is_empty())
+            .zip(&materialized_join_cols)
+        {
+            hash_rows(rows, &mut hashes, &self.hb);
+            self.ooc_state.dump(&chunk.data, &hashes);
+            hashes.clear();
+        }
+        for ht in self.hash_tables.inner_mut() {
+            *ht = PlIdHashMap::with_capacity(HASHMAP_INIT_SIZE);
+        }
+    }
+
+    /// Create the operator that probes this build.
+    pub(super) fn finalize_operator(
+        &mut self,
+        context: &PExecutionContext,
+    ) -> PolarsResult<Box<dyn Operator>> {
+        if self.ooc_state.is_ooc() {
+            self.spill_in_memory();
+            if context.verbose {
+                eprintln!("finish streaming join build with spilled partitions")
+            }
+            let io_thread = self.ooc_state.take_io_thread().unwrap();
+            let probe_operator = GenericOocJoinProbe::new(
+                self.split_in_memory(),
+                io_thread,
+                self.hb.clone(),
+                self.join_columns_right.clone(),
+                self.join_nulls,
+            );
+            return Ok(Box::new(probe_operator));
+        }
+
+        let chunks_len = self.chunks.len();
+        let left_df = accumulate_dataframes_vertical_unchecked(
+            std::mem::take(&mut self.chunks)
+                .into_iter()
+                .map(|chunk| chunk.data),
+        );
+        if left_df.height() > 0 {
+            assert_eq!(left_df.n_chunks(), chunks_len);
+        }
+        // Reallocate to Arc<[]> to get rid of double indirection as this is accessed on every
+        // hashtable cmp.
+        let materialized_join_cols = Arc::from(std::mem::take(&mut self.materialized_join_cols));
+        let suffix = self.suffix.clone();
+        let hb = self.hb.clone();
+        let hash_tables = Arc::new(PartitionedHashMap::new(std::mem::take(
+            self.hash_tables.inner_mut(),
+        )));
+        let join_columns_left = self.join_columns_left.clone();
+        let join_columns_right = self.join_columns_right.clone();
+
+        // take the buffers, this saves one allocation
+        let mut hashes = std::mem::take(&mut self.hashes);
+        hashes.clear();
+
+        match self.join_type {
+            JoinType::Inner | JoinType::Left => {
+                let probe_operator = GenericJoinProbe::new(
+                    left_df,
+                    materialized_join_cols,
+                    suffix,
+                    hb,
+                    hash_tables,
+                    join_columns_left,
+                    join_columns_right,
+                    self.swapped,
+                    hashes,
+                    context,
+                    self.join_type.clone(),
+                    self.join_nulls,
+                );
+                Ok(Box::new(probe_operator))
+            },
+            JoinType::Outer { coalesce } => {
+                let probe_operator = GenericOuterJoinProbe::new(
+                    left_df,
+                    materialized_join_cols,
+                    suffix,
+                    hb,
+                    hash_tables,
+                    join_columns_left,
+                    self.swapped,
+                    hashes,
+                    self.join_nulls,
+                    coalesce,
+                    self.key_names_left.clone(),
+                    self.key_names_right.clone(),
+                );
+                Ok(Box::new(probe_operator))
+            },
+
+            _ => unimplemented!(),
+        }
+    }
 }
 
 impl<K: ExtraPayload> Sink for GenericBuild<K> {
@@ -180,6 +313,19 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         hash_rows(&rows, &mut hashes, &self.hb);
         self.hashes = hashes;
 
+        if self.ooc_state.check_memory_usage(&|| chunk.data.schema())? {
+            // the build doesn't fit in memory, spill this chunk together with the
+            // chunks that are still in memory
+            self.materialized_join_cols.pop();
+            self.spill_in_memory();
+            self.ooc_state.dump(&chunk.data, &self.hashes);
+
+            // clear memory
+            self.hashes.clear();
+            self.join_columns.clear();
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+
         let current_chunk_offset = self.chunks.len() as ChunkIdx;
 
         // row offset in the chunk belonging to the hash
@@ -212,6 +358,13 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
     }
 
     fn combine(&mut self, other: &mut dyn Sink) {
+        if self.ooc_state.is_ooc() {
+            // all build chunks go to disk, there is no point in merging the hash tables
+            let other = other.as_any().downcast_mut::<Self>().unwrap();
+            other.spill_in_memory();
+            self.spill_in_memory();
+            return;
+        }
         if self.is_empty() {
             let other = other.as_any().downcast_mut::<Self>().unwrap();
             if !other.is_empty() {
@@ -289,80 +442,157 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
             self.placeholder.clone(),
         );
         new.hb = self.hb.clone();
+        new.ooc_state = self.ooc_state.clone();
         Box::new(new)
     }
 
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
-        let chunks_len = self.chunks.len();
-        let left_df = accumulate_dataframes_vertical_unchecked(
-            std::mem::take(&mut self.chunks)
-                .into_iter()
-                .map(|chunk| chunk.data),
-        );
-        if left_df.height() > 0 {
-            assert_eq!(left_df.n_chunks(), chunks_len);
+        let probe_operator = self.finalize_operator(context)?;
+        self.placeholder.replace(probe_operator);
+        Ok(FinalizedSink::Operator)
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn fmt(&self) -> &str {
+        "generic_join_build"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_io::predicates::PhysicalIoExpr;
+    use polars_plan::dsl::{col, Expr};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::operators::{OperatorResult, SExecutionContext};
+
+    struct Context;
+
+    impl SExecutionContext for Context {
+        fn as_any(&self) -> &dyn Any {
+            self
         }
-        // Reallocate to Arc<[]> to get rid of double indirection as this is accessed on every
-        // hashtable cmp.
-        let materialized_join_cols = Arc::from(std::mem::take(&mut self.materialized_join_cols));
-        let suffix = self.suffix.clone();
-        let hb = self.hb.clone();
-        let hash_tables = Arc::new(PartitionedHashMap::new(std::mem::take(
-            self.hash_tables.inner_mut(),
-        )));
-        let join_columns_left = self.join_columns_left.clone();
-        let join_columns_right = self.join_columns_right.clone();
 
-        // take the buffers, this saves one allocation
-        let mut hashes = std::mem::take(&mut self.hashes);
-        hashes.clear();
+        fn should_stop(&self) -> PolarsResult<()> {
+            Ok(())
+        }
 
-        match self.join_type {
-            JoinType::Inner | JoinType::Left => {
-                let probe_operator = GenericJoinProbe::new(
-                    left_df,
-                    materialized_join_cols,
-                    suffix,
-                    hb,
-                    hash_tables,
-                    join_columns_left,
-                    join_columns_right,
-                    self.swapped,
-                    hashes,
-                    context,
-                    self.join_type.clone(),
-                    self.join_nulls,
-                );
-                self.placeholder.replace(Box::new(probe_operator));
-                Ok(FinalizedSink::Operator)
-            },
-            JoinType::Outer { coalesce } => {
-                let probe_operator = GenericOuterJoinProbe::new(
-                    left_df,
-                    materialized_join_cols,
-                    suffix,
-                    hb,
-                    hash_tables,
-                    join_columns_left,
-                    self.swapped,
-                    hashes,
-                    self.join_nulls,
-                    coalesce,
-                    self.key_names_left.clone(),
-                    self.key_names_right.clone(),
-                );
-                self.placeholder.replace(Box::new(probe_operator));
-                Ok(FinalizedSink::Operator)
-            },
+        fn policy_ctx_id(&self) -> Option<Uuid> {
+            None
+        }
+    }
 
-            _ => unimplemented!(),
+    struct Column(&'static str);
+
+    impl PhysicalIoExpr for Column {
+        fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+            df.column(self.0).cloned()
         }
     }
 
-    fn as_any(&mut self) -> &mut dyn Any {
-        self
+    impl PhysicalPipedExpr for Column {
+        fn evaluate(&self, chunk: &DataChunk, _lazy_state: &dyn Any) -> PolarsResult<Series> {
+            self.evaluate_io(&chunk.data)
+        }
+
+        fn field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+            input_schema.try_get_field(self.0)
+        }
+
+        fn expression(&self) -> Expr {
+            col(self.0)
+        }
+
+        fn get_uuid(&self) -> PolarsResult<Uuid> {
+            Ok(Uuid::nil())
+        }
     }
-    fn fmt(&self) -> &str {
-        "generic_join_build"
+
+    fn chunks(n: usize, value_name: &str) -> Vec<DataChunk> {
+        (0..n)
+            .map(|i| {
+                let keys = (0..50)
+                    .map(|j| ((i * 50 + j) % 70) as i32)
+                    .collect::<Vec<_>>();
+                let values = (0..50).map(|j| (i * 50 + j) as i32).collect::<Vec<_>>();
+                let df = df![
+                    "key" => keys,
+                    value_name => values,
+                ]
+                .unwrap();
+                DataChunk::new(i as IdxSize, df)
+            })
+            .collect()
+    }
+
+    /// Join the build chunks with the probe chunks. If `spill_after` is set, the build
+    /// goes out-of-core once that many chunks are in memory.
+    fn join(spill_after: Option<usize>) -> DataFrame {
+        let context = PExecutionContext::new(Box::new(Context), false);
+        let key: Arc<Vec<Arc<dyn PhysicalPipedExpr>>> = Arc::new(vec![Arc::new(Column("key"))]);
+        let mut build = GenericBuild::<()>::new(
+            Arc::from("_right"),
+            JoinType::Inner,
+            false,
+            key.clone(),
+            key,
+            false,
+            Node::default(),
+            vec![].into(),
+            vec![].into(),
+            PlaceHolder::new(),
+        );
+        for (i, chunk) in chunks(6, "left").into_iter().enumerate() {
+            if spill_after == Some(i) {
+                build.ooc_state.init_ooc(chunk.data.schema()).unwrap();
+            }
+            build.sink(&context, chunk).unwrap();
+        }
+        let mut probe = build.finalize_operator(&context).unwrap();
+
+        let mut out = vec![];
+        for chunk in chunks(4, "right") {
+            loop {
+                match probe.execute(&context, &chunk).unwrap() {
+                    OperatorResult::HaveMoreOutPut(chunk) => out.push(chunk.data),
+                    OperatorResult::Finished(chunk) => {
+                        out.push(chunk.data);
+                        break;
+                    },
+                    OperatorResult::NeedsNewData => break,
+                }
+            }
+        }
+        if probe.must_flush() {
+            loop {
+                match probe.flush(&context).unwrap() {
+                    OperatorResult::HaveMoreOutPut(chunk) => out.push(chunk.data),
+                    OperatorResult::Finished(chunk) => {
+                        out.push(chunk.data);
+                        break;
+                    },
+                    OperatorResult::NeedsNewData => break,
+                }
+            }
+        }
+        accumulate_dataframes_vertical_unchecked(out)
+            .sort(["left", "right"], Default::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_spill_after_in_memory_chunks() {
+        let expected = join(None);
+        assert!(expected.height() > 0);
+        for spill_after in [0, 1, 3, 5] {
+            let out = join(Some(spill_after));
+            assert!(
+                out.equals_missing(&expected),
+                "spill after {spill_after} chunks"
+            );
+        }
     }
 }
@@ -245,6 +245,11 @@ impl<K: ExtraPayload> GenericOuterJoinProbe<K> {
             })
         };
         let out = self.finish_join(left_df, right_df)?;
+
+        // Clear memory.
+        self.row_values.clear();
+        self.hashes.clear();
+
         Ok(OperatorResult::Finished(chunk.with_data(out)))
     }
 
@@ -297,7 +302,7 @@ impl<K: ExtraPayload> Operator for GenericOuterJoinProbe<K> {
         self.execute_outer(context, chunk)
     }
 
-    fn flush(&mut self) -> PolarsResult<OperatorResult> {
+    fn flush(&mut self, _context: &PExecutionContext) -> PolarsResult<OperatorResult> {
         self.execute_flush()
     }
 
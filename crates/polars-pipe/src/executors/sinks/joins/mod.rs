@@ -3,6 +3,7 @@ mod cross;
 mod generic_build;
 mod generic_probe_inner_left;
 mod generic_probe_outer;
+mod ooc;
 mod row_values;
 
 use std::hash::{BuildHasherDefault, Hash, Hasher};
@@ -0,0 +1,403 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use polars_core::config::verbose;
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::*;
+use polars_core::utils::_set_partition_size;
+use polars_core::utils::flatten::flatten_df_iter;
+use polars_io::ipc::IpcReader;
+use polars_io::SerReader;
+
+use super::generic_build::GenericBuild;
+use super::row_values::RowValues;
+use super::ExtraPayload;
+use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
+use crate::executors::sinks::memory::{MemTracker, TO_DISK_THRESHOLD};
+use crate::executors::sinks::utils::hash_rows;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext, Sink};
+use crate::pipeline::{morsels_per_sink, FORCE_OOC, PARTITION_SIZE};
+
+type IOThreadRef = Arc<Mutex<Option<IOThread>>>;
+
+/// The partition a row is spilled to. This uses other bits of the hash than the
+/// partitioned hash tables, so that the rows of a spilled partition are still spread
+/// over all of them when the partition is joined.
+#[inline]
+fn spill_partition(h: u64) -> usize {
+    (h >> 32) as usize % PARTITION_SIZE
+}
+
+/// Split `df` in the spill partitions of its rows and send them to the IO thread.
+fn dump_partitioned(io_thread: &IOThread, df: &DataFrame, hashes: &[u64]) {
+    debug_assert_eq!(df.height(), hashes.len());
+    let mut partitions = vec![vec![]; PARTITION_SIZE];
+    for (i, h) in hashes.iter().enumerate() {
+        partitions[spill_partition(*h)].push(i as IdxSize);
+    }
+
+    let (partition_nos, dfs): (Vec<_>, Vec<_>) = partitions
+        .into_iter()
+        .enumerate()
+        .filter(|(_, idx)| !idx.is_empty())
+        // SAFETY: the indices are in bounds.
+        .map(|(i, idx)| {
+            (i as IdxSize, unsafe {
+                df._take_unchecked_slice(&idx, false)
+            })
+        })
+        .unzip();
+    io_thread.dump_iter(
+        Some(IdxCa::from_vec("", partition_nos)),
+        Box::new(dfs.into_iter()),
+    )
+}
+
+fn read_spilled(path: PathBuf) -> PolarsResult<DataFrame> {
+    let file = polars_utils::open_file(path)?;
+    IpcReader::new(file).finish()
+}
+
+/// Decides when the build side of a join goes out-of-core. This uses the same
+/// memory-pressure heuristic as the out-of-core group_by.
+#[derive(Clone)]
+pub(super) struct OocState {
+    // Stores available memory in the system at the start of this sink.
+    mem_track: MemTracker,
+    // whether this thread spills its build chunks
+    pub(super) ooc: bool,
+    // when ooc, we write to disk using an IO thread that is shared by all threads
+    io_thread: IOThreadRef,
+    to_disk_threshold: f64,
+}
+
+impl OocState {
+    pub(super) fn new() -> Self {
+        let to_disk_threshold = if std::env::var(FORCE_OOC).is_ok() {
+            1.0
+        } else {
+            TO_DISK_THRESHOLD
+        };
+        Self {
+            mem_track: MemTracker::new(morsels_per_sink()),
+            ooc: false,
+            io_thread: Default::default(),
+            to_disk_threshold,
+        }
+    }
+
+    /// A state that never goes out-of-core. The spilled partitions are joined in memory.
+    pub(super) fn in_memory() -> Self {
+        Self {
+            to_disk_threshold: 0.0,
+            ..Self::new()
+        }
+    }
+
+    /// Returns `true` if the build chunks must be spilled. Once one thread goes
+    /// out-of-core, the other threads follow.
+    pub(super) fn check_memory_usage(
+        &mut self,
+        spill_schema: &dyn Fn() -> Schema,
+    ) -> PolarsResult<bool> {
+        if self.ooc {
+            return Ok(true);
+        }
+        if self.is_ooc()
+            || self.mem_track.free_memory_fraction_since_start() < self.to_disk_threshold
+        {
+            self.init_ooc(spill_schema())?;
+        }
+        Ok(self.ooc)
+    }
+
+    pub(super) fn init_ooc(&mut self, spill_schema: Schema) -> PolarsResult<()> {
+        let mut iot = self.io_thread.lock().unwrap();
+        if iot.is_none() {
+            if verbose() {
+                eprintln!("OOC join started");
+            }
+            *iot = Some(IOThread::try_new(Arc::new(spill_schema), "join")?);
+        }
+        self.ooc = true;
+        Ok(())
+    }
+
+    /// Whether any thread of the build went out-of-core.
+    pub(super) fn is_ooc(&self) -> bool {
+        self.io_thread.lock().unwrap().is_some()
+    }
+
+    pub(super) fn take_io_thread(&self) -> Option<IOThread> {
+        self.io_thread.lock().unwrap().take()
+    }
+
+    pub(super) fn dump(&self, df: &DataFrame, hashes: &[u64]) {
+        let iot = self.io_thread.lock().unwrap();
+        dump_partitioned(iot.as_ref().unwrap(), df, hashes)
+    }
+}
+
+/// State of the probe phase that is shared by all threads.
+struct SpilledJoin<K: ExtraPayload> {
+    // An empty build, every spilled partition is joined by an in-memory split of this.
+    build: GenericBuild<K>,
+    build_io_thread: IOThread,
+    probe_io_thread: Mutex<Option<IOThread>>,
+    // Empty frames that have the schema of the build and probe side.
+    build_dummy: DataFrame,
+    probe_dummy: OnceLock<DataFrame>,
+    next_partition: AtomicUsize,
+}
+
+/// A spilled partition that is being joined in memory.
+struct PartitionJoin {
+    partition: usize,
+    operator: Box<dyn Operator>,
+    probe_files: Vec<PathBuf>,
+    probe_chunk: Option<DataChunk>,
+    // An outer join flushes the unmatched build rows of every hash table partition.
+    n_flush: usize,
+}
+
+impl PartitionJoin {
+    fn next_output(&mut self, context: &PExecutionContext) -> PolarsResult<Option<DataChunk>> {
+        loop {
+            if let Some(chunk) = &self.probe_chunk {
+                match self.operator.execute(context, chunk)? {
+                    OperatorResult::Finished(out) => {
+                        self.probe_chunk = None;
+                        return Ok(Some(out));
+                    },
+                    OperatorResult::HaveMoreOutPut(out) => return Ok(Some(out)),
+                    OperatorResult::NeedsNewData => self.probe_chunk = None,
+                }
+            } else if let Some(path) = self.probe_files.pop() {
+                self.probe_chunk = Some(DataChunk::new(0, read_spilled(path)?));
+            } else if self.n_flush > 0 {
+                self.n_flush -= 1;
+                // The outer join probe flushes the hash table partition of its thread.
+                let mut flush_op = self.operator.split(self.n_flush);
+                match flush_op.flush(context)? {
+                    OperatorResult::Finished(out) | OperatorResult::HaveMoreOutPut(out) => {
+                        return Ok(Some(out))
+                    },
+                    OperatorResult::NeedsNewData => {},
+                }
+            } else {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+/// Probe operator of a join of which the build side didn't fit in memory. This is a grace
+/// hash join: the probe chunks are partitioned by the hashes of their keys and spilled,
+/// like the build chunks were. When flushed, the matching partitions of both sides are
+/// joined in memory, one partition at a time. The output order is not maintained.
+pub(super) struct GenericOocJoinProbe<K: ExtraPayload> {
+    shared: Arc<SpilledJoin<K>>,
+    hb: RandomState,
+    join_nulls: bool,
+    row_values: RowValues,
+    hashes: Vec<u64>,
+    // cached empty output, returned when probe chunks are spilled
+    empty_output: Option<DataFrame>,
+    partition: Option<PartitionJoin>,
+}
+
+impl<K: ExtraPayload> GenericOocJoinProbe<K> {
+    pub(super) fn new(
+        build: GenericBuild<K>,
+        build_io_thread: IOThread,
+        hb: RandomState,
+        join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        join_nulls: bool,
+    ) -> Self {
+        block_thread_until_io_thread_done(&build_io_thread);
+        let build_dummy = DataFrame::from(build_io_thread.schema.as_ref());
+        let shared = SpilledJoin {
+            build,
+            build_io_thread,
+            probe_io_thread: Default::default(),
+            build_dummy,
+            probe_dummy: Default::default(),
+            next_partition: Default::default(),
+        };
+        Self {
+            shared: Arc::new(shared),
+            hb,
+            join_nulls,
+            row_values: RowValues::new(join_columns_right, false),
+            hashes: vec![],
+            empty_output: None,
+            partition: None,
+        }
+    }
+
+    /// Prepare the in-memory join of a spilled partition.
+    fn build_partition(
+        &self,
+        context: &PExecutionContext,
+        partition: Option<usize>,
+    ) -> PolarsResult<Box<dyn Operator>> {
+        let shared = self.shared.as_ref();
+        let mut build = shared.build.split_in_memory();
+        // Ensure the join can be finished if the partition has no build rows.
+        build.sink(context, DataChunk::new(0, shared.build_dummy.clone()))?;
+
+        if let Some(partition) = partition {
+            let io_thread = &shared.build_io_thread;
+            let mut partition_dir = io_thread.dir.clone();
+            partition_dir.push(format!("{partition}"));
+            if partition_dir.exists() {
+                for file in std::fs::read_dir(&partition_dir)? {
+                    let spilled = read_spilled(file?.path())?;
+                    for df in flatten_df_iter(&spilled) {
+                        build.sink(context, DataChunk::new(0, df))?;
+                    }
+                }
+                io_thread.clean(partition_dir);
+            }
+        }
+        build.finalize_operator(context)
+    }
+
+    fn next_partition(&self, context: &PExecutionContext) -> PolarsResult<Option<PartitionJoin>> {
+        let shared = self.shared.as_ref();
+        let partition = shared.next_partition.fetch_add(1, Ordering::Relaxed);
+        if partition >= PARTITION_SIZE {
+            return Ok(None);
+        }
+        if context.verbose {
+            eprintln!("process partition {partition} during {}", self.fmt())
+        }
+
+        let mut probe_files = vec![];
+        if let Some(io_thread) = shared.probe_io_thread.lock().unwrap().as_ref() {
+            block_thread_until_io_thread_done(io_thread);
+            let mut partition_dir = io_thread.dir.clone();
+            partition_dir.push(format!("{partition}"));
+            if partition_dir.exists() {
+                for file in std::fs::read_dir(partition_dir)? {
+                    probe_files.push(file?.path());
+                }
+            }
+        }
+
+        let operator = self.build_partition(context, Some(partition))?;
+        let (probe_chunk, n_flush) = if operator.must_flush() {
+            // The outer join probe needs a probe chunk before it can be flushed.
+            let probe_dummy = shared.probe_dummy.get().unwrap().clone();
+            (Some(DataChunk::new(0, probe_dummy)), _set_partition_size())
+        } else {
+            (None, 0)
+        };
+        Ok(Some(PartitionJoin {
+            partition,
+            operator,
+            probe_files,
+            probe_chunk,
+            n_flush,
+        }))
+    }
+
+    fn get_empty_output(&mut self, context: &PExecutionContext) -> PolarsResult<DataFrame> {
+        if self.empty_output.is_none() {
+            let mut operator = self.build_partition(context, None)?;
+            let probe_dummy = self.shared.probe_dummy.get().unwrap().clone();
+            let out = match operator.execute(context, &DataChunk::new(0, probe_dummy))? {
+                OperatorResult::Finished(out) | OperatorResult::HaveMoreOutPut(out) => out.data,
+                OperatorResult::NeedsNewData => unreachable!(),
+            };
+            self.empty_output = Some(out.clear());
+        }
+        Ok(self.empty_output.clone().unwrap())
+    }
+
+    fn spill_probe_chunk(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<()> {
+        let mut hashes = std::mem::take(&mut self.hashes);
+        let rows = self
+            .row_values
+            .get_values(context, chunk, self.join_nulls)?;
+        hash_rows(&rows, &mut hashes, &self.hb);
+
+        let mut iot = self.shared.probe_io_thread.lock().unwrap();
+        if iot.is_none() {
+            *iot = Some(IOThread::try_new(Arc::new(chunk.data.schema()), "join")?);
+        }
+        dump_partitioned(iot.as_ref().unwrap(), &chunk.data, &hashes);
+
+        // Clear memory.
+        self.row_values.clear();
+        hashes.clear();
+        self.hashes = hashes;
+        Ok(())
+    }
+}
+
+impl<K: ExtraPayload> Operator for GenericOocJoinProbe<K> {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        self.shared.probe_dummy.get_or_init(|| chunk.data.clear());
+        if !chunk.is_empty() {
+            self.spill_probe_chunk(context, chunk)?;
+        }
+        let out = self.get_empty_output(context)?;
+        Ok(OperatorResult::Finished(chunk.with_data(out)))
+    }
+
+    fn flush(&mut self, context: &PExecutionContext) -> PolarsResult<OperatorResult> {
+        loop {
+            if let Some(partition) = &mut self.partition {
+                if let Some(out) = partition.next_output(context)? {
+                    return Ok(OperatorResult::HaveMoreOutPut(out));
+                }
+                let partition = self.partition.take().unwrap().partition;
+                if let Some(io_thread) = self.shared.probe_io_thread.lock().unwrap().as_ref() {
+                    let mut partition_dir = io_thread.dir.clone();
+                    partition_dir.push(format!("{partition}"));
+                    if partition_dir.exists() {
+                        io_thread.clean(partition_dir);
+                    }
+                }
+            }
+            match self.next_partition(context)? {
+                Some(partition) => self.partition = Some(partition),
+                None => {
+                    let out = self.get_empty_output(context)?;
+                    return Ok(OperatorResult::Finished(DataChunk::new(0, out)));
+                },
+            }
+        }
+    }
+
+    fn must_flush(&self) -> bool {
+        true
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(Self {
+            shared: self.shared.clone(),
+            hb: self.hb.clone(),
+            join_nulls: self.join_nulls,
+            row_values: self.row_values.clone(),
+            hashes: vec![],
+            empty_output: None,
+            partition: None,
+        })
+    }
+
+    fn fmt(&self) -> &str {
+        "generic_ooc_join_probe"
+    }
+}
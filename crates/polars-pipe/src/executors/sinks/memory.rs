@@ -6,6 +6,9 @@ use polars_utils::sys::MEMINFO;
 use crate::pipeline::FORCE_OOC;
 
 const TO_MB: usize = 2 << 19;
+// If the fraction of free memory drops below this, the out-of-core
+// operations spill to disk and finish in a second run.
+pub(super) const TO_DISK_THRESHOLD: f64 = 0.3;
 
 #[derive(Clone)]
 pub(super) struct MemTracker {
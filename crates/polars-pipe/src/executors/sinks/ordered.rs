@@ -1,5 +1,7 @@
 use std::any::Any;
 
+use picachv::plan_argument::Argument;
+use picachv::{PlanArgument, TransformArgument, TransformInfo};
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
@@ -46,7 +48,7 @@ impl Sink for OrderedSink {
     fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
         Box::new(self.clone())
     }
-    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         if self.chunks.is_empty() {
             return Ok(FinalizedSink::Finished(DataFrame::from(
                 self.schema.as_ref(),
@@ -55,7 +57,25 @@ impl Sink for OrderedSink {
         self.sort();
 
         let chunks = std::mem::take(&mut self.chunks);
-        Ok(FinalizedSink::Finished(chunks_to_df_unchecked(chunks)))
+        let uuids = chunks
+            .iter()
+            .map(|chunk| chunk.data.get_uuid())
+            .collect::<Vec<_>>();
+        let mut df = chunks_to_df_unchecked(chunks);
+        if context.policy_check() {
+            // Tell the monitor that the chunks are concatenated in order.
+            let mut uuids = uuids.into_iter();
+            let first = uuids.next().unwrap();
+            let df_uuid = uuids.try_fold(first, |lhs, rhs| {
+                let plan_arg = PlanArgument {
+                    argument: Some(Argument::Transform(TransformArgument {})),
+                    transform_info: Some(TransformInfo::from_union(lhs, rhs)?),
+                };
+                context.execute_epilogue(lhs, plan_arg, "transform")
+            })?;
+            df.set_uuid(df_uuid);
+        }
+        Ok(FinalizedSink::Finished(df))
     }
     fn as_any(&mut self) -> &mut dyn Any {
         self
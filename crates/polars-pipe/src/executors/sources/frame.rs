@@ -2,32 +2,57 @@ use std::iter::Enumerate;
 use std::vec::IntoIter;
 
 use polars_core::error::PolarsResult;
-use polars_core::frame::DataFrame;
+use polars_core::frame::{DataFrame, DataFramePolicy};
 use polars_core::utils::split_df;
 use polars_core::POOL;
 use polars_utils::IdxSize;
+use uuid::Uuid;
 
-use crate::executors::sources::get_source_index;
+use crate::executors::sources::{get_source_index, ChunkReporter};
 use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
 
 pub struct DataFrameSource {
     dfs: Enumerate<IntoIter<DataFrame>>,
     n_threads: usize,
+    // Needed to report the chunks to the monitor.
+    df_uuid: Uuid,
+    // Registered with the monitor on the first batch, replaces `df_uuid`.
+    policy: Option<DataFramePolicy>,
+    project_list: Option<Vec<u64>>,
+    height: usize,
+    reporter: Option<ChunkReporter>,
 }
 
 impl DataFrameSource {
     pub(crate) fn from_df(mut df: DataFrame) -> Self {
         let n_threads = POOL.current_num_threads();
+        let df_uuid = df.get_uuid();
+        let policy = df.get_policy().cloned();
+        let height = df.height();
         let dfs = split_df(&mut df, n_threads).unwrap();
         let dfs = dfs.into_iter().enumerate();
-        Self { dfs, n_threads }
+        Self {
+            dfs,
+            n_threads,
+            df_uuid,
+            policy,
+            project_list: None,
+            height,
+            reporter: None,
+        }
+    }
+
+    /// The columns of the registered dataframe that were selected before it became a source.
+    pub(crate) fn with_project_list(mut self, project_list: Option<Vec<u64>>) -> Self {
+        self.project_list = project_list;
+        self
     }
 }
 
 impl Source for DataFrameSource {
-    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
         let idx_offset = get_source_index(0);
-        let chunks = (&mut self.dfs)
+        let mut chunks = (&mut self.dfs)
             .map(|(chunk_index, data)| DataChunk {
                 chunk_index: (chunk_index as u32 + idx_offset) as IdxSize,
                 data,
@@ -36,6 +61,21 @@ impl Source for DataFrameSource {
             .collect::<Vec<_>>();
         get_source_index(chunks.len() as u32);
 
+        if context.policy_check() {
+            if let (Some(policy), Some(ctx_id)) = (self.policy.take(), context.policy_ctx_id) {
+                self.df_uuid = policy.register(ctx_id)?;
+            }
+            let (df_uuid, height) = (self.df_uuid, self.height);
+            let reporter = self
+                .reporter
+                .get_or_insert_with(|| ChunkReporter::new(df_uuid, height));
+            for chunk in &mut chunks {
+                let uuid =
+                    reporter.report(context, self.project_list.as_deref(), chunk.data.height())?;
+                chunk.data.set_uuid(uuid);
+            }
+        }
+
         if chunks.is_empty() {
             Ok(SourceResult::Finished)
         } else {
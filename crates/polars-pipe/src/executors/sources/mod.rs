@@ -15,14 +15,80 @@ pub(crate) use frame::*;
 pub(crate) use ipc_one_shot::*;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::*;
+use picachv::get_data_argument::DataSource;
+use picachv::get_data_in_memory::ProjectList;
+use picachv::plan_argument::Argument;
+use picachv::transform_info::Information;
+use picachv::{GetDataArgument, GetDataInMemory, PlanArgument, ReorderInformation, TransformInfo};
+use polars_core::error::{polars_ensure, PolarsResult};
 pub(crate) use reproject::*;
 pub(crate) use union::*;
+use uuid::Uuid;
 
 #[cfg(feature = "csv")]
 use super::*;
+use crate::operators::PExecutionContext;
 
 static CHUNK_INDEX: AtomicU32 = AtomicU32::new(0);
 
 pub(super) fn get_source_index(add: u32) -> u32 {
     CHUNK_INDEX.fetch_add(add, Ordering::Relaxed)
 }
+
+/// Reports the chunks a source produces from a registered dataframe to the monitor, in order.
+///
+/// A chunk is reported as the range of rows of the dataframe it holds, so reporting a chunk
+/// costs time linear in its height and reporting all chunks costs time linear in the height of
+/// the dataframe.
+pub(super) struct ChunkReporter {
+    df_uuid: Uuid,
+    height: usize,
+    offset: usize,
+}
+
+impl ChunkReporter {
+    /// A reporter for the registered dataframe `df_uuid` of `height` rows.
+    pub(super) fn new(df_uuid: Uuid, height: usize) -> Self {
+        ChunkReporter {
+            df_uuid,
+            height,
+            offset: 0,
+        }
+    }
+
+    /// Report that the next `len` rows of the dataframe form a chunk. Returns the uuid of the
+    /// chunk.
+    pub(super) fn report(
+        &mut self,
+        context: &PExecutionContext,
+        project_list: Option<&[u64]>,
+        len: usize,
+    ) -> PolarsResult<Uuid> {
+        let range = self.offset..self.offset + len;
+        polars_ensure!(
+            range.end <= self.height,
+            ComputeError: "source produced more rows than the registered dataframe holds"
+        );
+        self.offset = range.end;
+        // The chunk takes the rows in the range of the dataframe, in order.
+        let transform_info = TransformInfo {
+            information: Some(Information::Reorder(ReorderInformation {
+                perm: range.map(|i| i as u64).collect(),
+            })),
+        };
+
+        let plan_arg = PlanArgument {
+            argument: Some(Argument::GetData(GetDataArgument {
+                data_source: Some(DataSource::InMemory(GetDataInMemory {
+                    df_uuid: self.df_uuid.to_bytes_le().to_vec(),
+                    pred: None,
+                    project_list: project_list.map(|project_list| ProjectList {
+                        project_list: project_list.to_vec(),
+                    }),
+                })),
+            })),
+            transform_info: Some(transform_info),
+        };
+        context.execute_epilogue(self.df_uuid, plan_arg, "scan")
+    }
+}
@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use arrow::datatypes::ArrowSchemaRef;
+use picachv::native::register_policy_dataframe_parquet;
 use polars_core::config::{self, get_file_prefetch_size};
 use polars_core::error::*;
 use polars_core::prelude::Series;
@@ -18,11 +19,12 @@ use polars_io::prelude::ParquetAsyncReader;
 use polars_io::utils::check_projected_arrow_schema;
 use polars_io::{is_cloud_url, SerReader};
 use polars_plan::logical_plan::FileInfo;
-use polars_plan::prelude::{FileScanOptions, ParquetOptions};
+use polars_plan::prelude::{FileScanOptions, ParquetOptions, ScanPolicy};
 use polars_utils::iter::EnumerateIdxTrait;
 use polars_utils::IdxSize;
+use uuid::Uuid;
 
-use crate::executors::sources::get_source_index;
+use crate::executors::sources::{get_source_index, ChunkReporter};
 use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
 use crate::pipeline::determine_chunk_size;
 
@@ -42,6 +44,9 @@ pub struct ParquetSource {
     run_async: bool,
     prefetch_size: usize,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    with_policy: Option<Arc<ScanPolicy>>,
+    // Reports the chunks of the registered policy dataframe.
+    reporter: Option<ChunkReporter>,
 }
 
 impl ParquetSource {
@@ -195,6 +200,7 @@ impl ParquetSource {
         file_info: FileInfo,
         verbose: bool,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        with_policy: Option<Arc<ScanPolicy>>,
     ) -> PolarsResult<Self> {
         let n_threads = POOL.current_num_threads();
 
@@ -221,6 +227,8 @@ impl ParquetSource {
             run_async,
             prefetch_size,
             predicate,
+            with_policy,
+            reporter: None,
         };
         // Already start downloading when we deal with cloud urls.
         if run_async {
@@ -230,6 +238,61 @@ impl ParquetSource {
         Ok(source)
     }
 
+    /// Register the policy of the files with the monitor. The policy covers the rows of all
+    /// files, so we need the total number of rows to report which rows a chunk contains.
+    fn register_policy(&mut self, ctx_id: Uuid) -> PolarsResult<()> {
+        if self.reporter.is_some() {
+            return Ok(());
+        }
+        let Some(policy) = self.with_policy.as_ref() else {
+            polars_bail!(InvalidOperation: "Policy check requested but no policy was provided")
+        };
+        let ScanPolicy::File(policy) = policy.as_ref() else {
+            polars_bail!(
+                InvalidOperation: "policies resolved per file are not supported in the streaming engine"
+            )
+        };
+        polars_ensure!(
+            !self.run_async,
+            InvalidOperation: "policy checking of cloud parquet scans is not supported in the streaming engine"
+        );
+        polars_ensure!(
+            self.file_info.hive_parts.is_none(),
+            InvalidOperation: "policy checking of hive partitioned scans is not supported in the streaming engine"
+        );
+
+        let height = self
+            .paths
+            .iter()
+            .map(|path| ParquetReader::new(std::fs::File::open(path)?).num_rows())
+            .sum::<PolarsResult<usize>>()?;
+        let projection = materialize_projection(
+            self.file_options
+                .with_columns
+                .as_deref()
+                .map(|cols| cols.deref()),
+            &self.file_info.schema,
+            None,
+            false,
+        )
+        .unwrap_or_else(|| {
+            let n_cols = self
+                .file_info
+                .reader_schema
+                .as_ref()
+                .map(|schema| schema.fields.len())
+                .unwrap_or(self.file_info.schema.len());
+            (0..n_cols).collect()
+        });
+        let path = policy.to_str().ok_or_else(
+            || polars_err!(InvalidOperation: "policy path {} is not valid UTF-8", policy.display()),
+        )?;
+        let df_uuid = register_policy_dataframe_parquet(ctx_id, path, &projection, None)?;
+
+        self.reporter = Some(ChunkReporter::new(df_uuid, height));
+        Ok(())
+    }
+
     fn prefetch_files(&mut self) -> PolarsResult<()> {
         // We already start downloading the next file, we can only do that if we don't have a limit.
         // In the case of a limit we first must update the row count with the batch results.
@@ -273,7 +336,7 @@ impl ParquetSource {
 }
 
 impl Source for ParquetSource {
-    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
         self.prefetch_files()?;
 
         let Some(mut reader) = self.batched_readers.pop_front() else {
@@ -292,11 +355,15 @@ impl Source for ParquetSource {
 
                 // reset the reader
                 self.init_next_reader()?;
-                return self.get_batches(_context);
+                return self.get_batches(context);
             },
             Some(batches) => {
+                if let Some(ctx_id) = context.policy_ctx_id {
+                    self.register_policy(ctx_id)?;
+                }
+
                 let idx_offset = get_source_index(0);
-                let out = batches
+                let mut out = batches
                     .into_iter()
                     .enumerate_u32()
                     .map(|(i, data)| {
@@ -313,6 +380,30 @@ impl Source for ParquetSource {
                     .collect::<Vec<_>>();
                 get_source_index(out.len() as u32);
 
+                if let Some(reporter) = self.reporter.as_mut() {
+                    for chunk in &mut out {
+                        let project_list = self
+                            .file_options
+                            .with_columns
+                            .as_ref()
+                            .map(|columns| {
+                                columns
+                                    .iter()
+                                    .map(|name| {
+                                        chunk.data.try_get_column_index(name).map(|i| i as u64)
+                                    })
+                                    .collect::<PolarsResult<Vec<_>>>()
+                            })
+                            .transpose()?;
+                        let uuid = reporter.report(
+                            context,
+                            project_list.as_deref(),
+                            chunk.data.height(),
+                        )?;
+                        chunk.data.set_uuid(uuid);
+                    }
+                }
+
                 let result = SourceResult::GotMoreData(out);
                 // We are not yet done with this reader.
                 // Ensure it is used in next iteration.
@@ -3,6 +3,7 @@ use std::any::Any;
 use polars_core::prelude::*;
 use polars_io::predicates::PhysicalIoExpr;
 use polars_plan::dsl::Expr;
+use uuid::Uuid;
 
 use crate::operators::DataChunk;
 
@@ -14,4 +15,7 @@ pub trait PhysicalPipedExpr: PhysicalIoExpr + Send + Sync {
     fn field(&self, input_schema: &Schema) -> PolarsResult<Field>;
 
     fn expression(&self) -> Expr;
+
+    /// The uuid of this expression as registered with the monitor.
+    fn get_uuid(&self) -> PolarsResult<Uuid>;
 }
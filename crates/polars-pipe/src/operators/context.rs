@@ -1,24 +1,52 @@
 use std::any::Any;
 
+use picachv::native::execute_epilogue;
+use picachv::PlanArgument;
 use polars_core::prelude::*;
+use uuid::Uuid;
 
 pub trait SExecutionContext: Send + Sync {
     fn as_any(&self) -> &dyn Any;
 
     fn should_stop(&self) -> PolarsResult<()>;
+
+    /// The monitor context of this query, if policy checking is enabled.
+    fn policy_ctx_id(&self) -> Option<Uuid>;
 }
 
 pub struct PExecutionContext {
     // injected upstream in polars-lazy
     pub(crate) execution_state: Box<dyn SExecutionContext>,
     pub(crate) verbose: bool,
+    pub(crate) policy_ctx_id: Option<Uuid>,
 }
 
 impl PExecutionContext {
     pub(crate) fn new(state: Box<dyn SExecutionContext>, verbose: bool) -> Self {
+        let policy_ctx_id = state.policy_ctx_id();
         PExecutionContext {
             execution_state: state,
             verbose,
+            policy_ctx_id,
+        }
+    }
+
+    pub(crate) fn policy_check(&self) -> bool {
+        self.policy_ctx_id.is_some()
+    }
+
+    /// Report `plan_arg` of the `operator` on the chunk identified by `df_uuid` to the monitor
+    /// and return the uuid of the resulting chunk.
+    pub(crate) fn execute_epilogue(
+        &self,
+        df_uuid: Uuid,
+        plan_arg: PlanArgument,
+        operator: &str,
+    ) -> PolarsResult<Uuid> {
+        match self.policy_ctx_id {
+            Some(ctx_id) => execute_epilogue(ctx_id, df_uuid, Some(plan_arg))
+                .map_err(|err| PolarsError::from(err).with_policy_context(operator, None)),
+            None => Ok(df_uuid),
         }
     }
 }
@@ -17,7 +17,7 @@ pub trait Operator: Send + Sync {
         chunk: &DataChunk,
     ) -> PolarsResult<OperatorResult>;
 
-    fn flush(&mut self) -> PolarsResult<OperatorResult> {
+    fn flush(&mut self, _context: &PExecutionContext) -> PolarsResult<OperatorResult> {
         unimplemented!()
     }
 
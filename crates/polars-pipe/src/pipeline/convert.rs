@@ -22,6 +22,15 @@ use crate::pipeline::{PhysOperator, PipeLine};
 
 pub type CallBacks = PlHashMap<Node, PlaceHolder>;
 
+/// The error for nodes that cannot report to the monitor yet. We rather fail than silently
+/// skip the policy check.
+fn policy_unsupported(node: &str) -> PolarsError {
+    polars_err!(
+        InvalidOperation: "{} is not supported by the streaming engine when policy checking is enabled; \
+        run the query without streaming", node
+    )
+}
+
 fn exprs_to_physical<F>(
     exprs: &[ExprIR],
     expr_arena: &Arena<AExpr>,
@@ -45,6 +54,7 @@ fn get_source<F>(
     to_physical: &F,
     push_predicate: bool,
     verbose: bool,
+    policy_check: bool,
 ) -> PolarsResult<Box<dyn Source>>
 where
     F: Fn(&ExprIR, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
@@ -59,6 +69,7 @@ where
             ..
         } => {
             let mut df = (*df).clone();
+            let mut project_list = None;
             if push_predicate {
                 if let Some(predicate) = selection {
                     let predicate = to_physical(&predicate, expr_arena, output_schema.as_ref())?;
@@ -68,10 +79,23 @@ where
                 }
                 // projection is free
                 if let Some(projection) = projection {
+                    if policy_check {
+                        project_list = Some(
+                            projection
+                                .iter()
+                                .map(|name| df.try_get_column_index(name).map(|i| i as u64))
+                                .collect::<PolarsResult<Vec<_>>>()?,
+                        );
+                    }
+                    let df_uuid = df.get_uuid();
+                    let policy = df.get_policy().cloned();
                     df = df.select(projection.as_slice())?;
+                    df.set_uuid(df_uuid);
+                    df.set_policy(policy);
                 }
             }
-            Ok(Box::new(sources::DataFrameSource::from_df(df)) as Box<dyn Source>)
+            let src = sources::DataFrameSource::from_df(df).with_project_list(project_list);
+            Ok(Box::new(src) as Box<dyn Source>)
         },
         Scan {
             paths,
@@ -84,15 +108,15 @@ where
         } => {
             // Add predicate to operators.
             // Except for parquet, as that format can use statistics to prune file/row-groups.
+            // With policy checking the source must read all rows, the monitor needs to know
+            // which rows the predicate removed.
             #[cfg(feature = "parquet")]
-            let is_parquet = matches!(scan_type, FileScan::Parquet { .. });
+            let is_parquet = matches!(scan_type, FileScan::Parquet { .. }) && !policy_check;
             #[cfg(not(feature = "parquet"))]
             let is_parquet = false;
 
             if let (false, true, Some(predicate)) = (is_parquet, push_predicate, predicate.clone())
             {
-                #[cfg(feature = "parquet")]
-                debug_assert!(!matches!(scan_type, FileScan::Parquet { .. }));
                 let predicate = to_physical(&predicate, expr_arena, output_schema.as_ref())?;
                 let op = operators::FilterOperator { predicate };
                 let op = Box::new(op) as Box<dyn Operator>;
@@ -103,6 +127,9 @@ where
                 FileScan::Csv {
                     options: csv_options,
                 } => {
+                    if policy_check {
+                        return Err(policy_unsupported("csv scan"));
+                    }
                     assert_eq!(paths.len(), 1);
                     let src = sources::CsvSource::new(
                         paths[0].clone(),
@@ -121,6 +148,7 @@ where
                 } => {
                     let predicate = predicate
                         .as_ref()
+                        .filter(|_| !policy_check)
                         .map(|predicate| {
                             let p = to_physical(predicate, expr_arena, output_schema.as_ref())?;
                             // Arc's all the way down. :(
@@ -150,6 +178,7 @@ where
                         file_info,
                         verbose,
                         predicate,
+                        with_policy,
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
@@ -166,11 +195,23 @@ pub fn get_sink<F>(
     expr_arena: &mut Arena<AExpr>,
     to_physical: &F,
     callbacks: &mut CallBacks,
+    policy_check: bool,
 ) -> PolarsResult<Box<dyn SinkTrait>>
 where
     F: Fn(&ExprIR, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
 {
     use IR::*;
+    if policy_check
+        && !matches!(
+            lp_arena.get(node),
+            Sink {
+                payload: SinkType::Memory,
+                ..
+            }
+        )
+    {
+        return Err(policy_unsupported(lp_arena.get(node).name()));
+    }
     let out = match lp_arena.get(node) {
         Sink { input, payload } => {
             let input_schema = lp_arena.get(*input).schema(lp_arena);
@@ -573,12 +614,16 @@ pub fn get_operator<F>(
     lp_arena: &Arena<IR>,
     expr_arena: &Arena<AExpr>,
     to_physical: &F,
+    policy_check: bool,
 ) -> PolarsResult<Box<dyn Operator>>
 where
     F: Fn(&ExprIR, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
 {
     use IR::*;
     let op = match lp_arena.get(node) {
+        MapFunction { function, .. } if policy_check => {
+            return Err(policy_unsupported(&format!("{function}")));
+        },
         SimpleProjection { input, columns, .. } => {
             let input_schema = lp_arena.get(*input).schema(lp_arena);
             let columns = columns.iter_names().cloned().collect();
@@ -675,6 +720,7 @@ pub fn create_pipeline<F>(
     // If the shared sink is already in cache, that one is used.
     sink_cache: &mut PlHashMap<usize, Box<dyn SinkTrait>>,
     callbacks: &mut CallBacks,
+    policy_check: bool,
 ) -> PolarsResult<PipeLine>
 where
     F: Fn(&ExprIR, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
@@ -693,6 +739,7 @@ where
                 &to_physical,
                 true,
                 verbose,
+                policy_check,
             )?,
             lp @ Scan { .. } => get_source(
                 lp.clone(),
@@ -701,6 +748,7 @@ where
                 &to_physical,
                 true,
                 verbose,
+                policy_check,
             )?,
             Union { inputs, .. } => {
                 let sources = inputs
@@ -716,6 +764,7 @@ where
                             &to_physical,
                             i == 0,
                             verbose && i == 0,
+                            policy_check,
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
@@ -738,11 +787,25 @@ where
             // ensure that shared sinks are really shared
             // to achieve this we store/fetch them in a cache
             let sink = if *shared_count.borrow() == 1 {
-                get_sink(node, lp_arena, expr_arena, &to_physical, callbacks)?
+                get_sink(
+                    node,
+                    lp_arena,
+                    expr_arena,
+                    &to_physical,
+                    callbacks,
+                    policy_check,
+                )?
             } else {
                 match sink_cache.entry(node.0) {
                     Entry::Vacant(entry) => {
-                        let sink = get_sink(node, lp_arena, expr_arena, &to_physical, callbacks)?;
+                        let sink = get_sink(
+                            node,
+                            lp_arena,
+                            expr_arena,
+                            &to_physical,
+                            callbacks,
+                            policy_check,
+                        )?;
                         entry.insert(sink.split(0));
                         sink
                     },
@@ -199,7 +199,7 @@ pub(super) fn flush_operators(
                     // The branch for flushing.
                     None => {
                         let op = operators.get_mut(op_i).unwrap().get_mut();
-                        match op.flush()? {
+                        match op.flush(ec)? {
                             OperatorResult::Finished(chunk) => {
                                 // Push the chunk in the next operator.
                                 in_process.push((op_i + 1, Some(chunk)))
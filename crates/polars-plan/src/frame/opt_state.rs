@@ -29,6 +29,12 @@ pub struct OptState {
     pub fast_projection: bool,
     /// Try to estimate the number of rows so that joins can determine which side to keep in memory.
     pub row_estimate: bool,
+    /// Reorder chains of inner joins by the estimated number of rows of the joined relations.
+    /// This does not maintain the row order.
+    pub join_reorder: bool,
+    /// Only apply the rewrites that keep the operators reported to the policy monitor intact.
+    /// This is set when the query is collected with policy checking enabled.
+    pub policy_checking: bool,
 }
 
 impl Default for OptState {
@@ -49,9 +55,48 @@ impl Default for OptState {
             fast_projection: true,
             eager: false,
             row_estimate: true,
+            join_reorder: false,
+            policy_checking: false,
         }
     }
 }
 
+impl OptState {
+    /// The enabled optimizations that are skipped because they change the operators reported
+    /// to the policy monitor. Common subplans would be read from `Cache` nodes, common
+    /// subexpressions and fused arithmetic would hide the expressions that were written and a
+    /// slice pushed into a scan would read fewer rows than the policy of the scan covers.
+    /// Reordered joins would report other intermediate results than the written ones.
+    ///
+    /// Predicate pushdown is not skipped, but predicates are not pushed past joins, group-bys
+    /// and distincts.
+    pub fn policy_skipped(&self) -> Vec<&'static str> {
+        let mut skipped = vec![];
+        if !self.policy_checking {
+            return skipped;
+        }
+        #[cfg(feature = "cse")]
+        {
+            if self.comm_subplan_elim {
+                skipped.push("comm_subplan_elim");
+            }
+            if self.comm_subexpr_elim {
+                skipped.push("comm_subexpr_elim");
+            }
+        }
+        #[cfg(feature = "fused")]
+        if self.simplify_expr {
+            skipped.push("fused_arithmetic");
+        }
+        if self.slice_pushdown {
+            skipped.push("slice_pushdown");
+        }
+        if self.join_reorder {
+            skipped.push("join_reorder");
+        }
+        skipped
+    }
+}
+
 /// AllowedOptimizations
 pub type AllowedOptimizations = OptState;
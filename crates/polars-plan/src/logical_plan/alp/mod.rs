@@ -38,8 +38,8 @@ pub enum IR {
         scan_type: FileScan,
         /// generic options that can be used for all file types.
         file_options: FileScanOptions,
-        /// The policy path.
-        with_policy: Option<Arc<PathBuf>>,
+        /// Where the policy of the scanned files is read from.
+        with_policy: Option<Arc<ScanPolicy>>,
     },
     DataFrameScan {
         df: Arc<DataFrame>,
@@ -43,7 +43,9 @@ impl DslBuilder {
         skip_rows: Option<usize>,
         n_rows: Option<usize>,
         name: &'static str,
+        with_policy: Option<ScanPolicy>,
     ) -> PolarsResult<Self> {
+        let with_policy = with_policy.map(Arc::new);
         let schema = match schema {
             Some(s) => s,
             None => function.schema(infer_schema_length)?,
@@ -76,7 +78,7 @@ impl DslBuilder {
                     skip_rows,
                 }),
             },
-            with_policy: None,
+            with_policy,
         }
         .into())
     }
@@ -94,10 +96,10 @@ impl DslBuilder {
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
         hive_options: HiveOptions,
-        with_policy: Option<std::path::PathBuf>,
+        with_policy: Option<ScanPolicy>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
-        let with_policy = with_policy.map(|e| e.into());
+        let with_policy = with_policy.map(Arc::new);
 
         let options = FileScanOptions {
             with_columns: None,
@@ -128,6 +130,7 @@ impl DslBuilder {
     }
 
     #[cfg(feature = "ipc")]
+    #[allow(clippy::too_many_arguments)]
     pub fn scan_ipc<P: Into<Arc<[std::path::PathBuf]>>>(
         paths: P,
         options: IpcScanOptions,
@@ -136,8 +139,10 @@ impl DslBuilder {
         row_index: Option<RowIndex>,
         rechunk: bool,
         cloud_options: Option<CloudOptions>,
+        with_policy: Option<ScanPolicy>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
+        let with_policy = with_policy.map(Arc::new);
 
         Ok(DslPlan::Scan {
             paths,
@@ -161,7 +166,7 @@ impl DslBuilder {
                 cloud_options,
                 metadata: None,
             },
-            with_policy: None,
+            with_policy,
         }
         .into())
     }
@@ -193,8 +198,10 @@ impl DslBuilder {
         truncate_ragged_lines: bool,
         n_threads: Option<usize>,
         decimal_float: bool,
+        with_policy: Option<ScanPolicy>,
     ) -> PolarsResult<Self> {
         let path = path.into();
+        let with_policy = with_policy.map(Arc::new);
 
         let paths = Arc::new([path]);
 
@@ -239,7 +246,7 @@ impl DslBuilder {
                     decimal_float,
                 },
             },
-            with_policy: None, // todo: make it path.
+            with_policy,
         }
         .into())
     }
@@ -0,0 +1,160 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+use crate::utils::expr_to_leaf_column_names_iter;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// Split a predicate into the predicates that are combined with `&`.
+fn split_conjunction(predicate: Expr, out: &mut Vec<Expr>) {
+    match predicate {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjunction(Arc::unwrap_or_clone(left), out);
+            split_conjunction(Arc::unwrap_or_clone(right), out);
+        },
+        predicate => out.push(predicate),
+    }
+}
+
+fn inequality_operator(op: Operator) -> Option<InequalityOperator> {
+    match op {
+        Operator::Lt => Some(InequalityOperator::Lt),
+        Operator::LtEq => Some(InequalityOperator::LtEq),
+        Operator::Gt => Some(InequalityOperator::Gt),
+        Operator::GtEq => Some(InequalityOperator::GtEq),
+        _ => None,
+    }
+}
+
+/// The columns of both sides of a join, by the names they have in the output of the join.
+struct JoinWhereColumns<'a> {
+    schema_left: &'a Schema,
+    /// The output name of every right column, mapped to its name in the right input.
+    right: PlHashMap<String, ColumnName>,
+}
+
+impl JoinWhereColumns<'_> {
+    /// The side all columns of `expr` come from, `None` if it has columns of both sides or no
+    /// columns at all.
+    fn side(&self, expr: &Expr) -> PolarsResult<Option<Side>> {
+        let mut side = None;
+        for name in expr_to_leaf_column_names_iter(expr) {
+            let column_side = if self.schema_left.contains(&name) {
+                Side::Left
+            } else if self.right.contains_key(name.as_ref()) {
+                Side::Right
+            } else {
+                polars_bail!(ColumnNotFound: "'{}' is not a column of either side of the join", name)
+            };
+            match side {
+                Some(side) if side != column_side => return Ok(None),
+                _ => side = Some(column_side),
+            }
+        }
+        Ok(side)
+    }
+
+    /// Rewrite an expression over the output of the join to one over the right input.
+    fn to_right_input(&self, expr: Expr) -> Expr {
+        expr.map_expr(|e| match e {
+            Expr::Column(name) => Expr::Column(self.right[name.as_ref()].clone()),
+            e => e,
+        })
+    }
+}
+
+impl DslBuilder {
+    /// Join with `other` on the rows for which all `predicates` hold. The predicates refer to
+    /// the columns by their names in the output of the join, so a column of `other` that is also
+    /// a column of this plan gets the suffix of `options`.
+    ///
+    /// Up to two inequalities between an expression of the left and one of the right side are
+    /// evaluated by a range join, the other predicates filter its output. If there are no such
+    /// inequalities, the predicates filter a cross join.
+    pub fn join_where(
+        self,
+        other: DslPlan,
+        predicates: Vec<Expr>,
+        mut options: JoinOptions,
+    ) -> PolarsResult<Self> {
+        let schema_left = self.0.compute_schema()?;
+        let schema_right = other.compute_schema()?;
+        let suffix = options.args.suffix();
+        let columns = JoinWhereColumns {
+            schema_left: &schema_left,
+            right: schema_right
+                .iter_names()
+                .map(|name| {
+                    let output_name = if schema_left.contains(name) {
+                        _join_suffix_name(name, suffix)
+                    } else {
+                        name.to_string()
+                    };
+                    (output_name, ColumnName::from(name.as_str()))
+                })
+                .collect(),
+        };
+
+        let mut conjunction = Vec::with_capacity(predicates.len());
+        for predicate in predicates {
+            split_conjunction(predicate, &mut conjunction);
+        }
+
+        let mut left_on = Vec::with_capacity(2);
+        let mut right_on = Vec::with_capacity(2);
+        let mut operators = Vec::with_capacity(2);
+        let mut remaining = Vec::new();
+        for predicate in conjunction {
+            if operators.len() < 2 {
+                if let Expr::BinaryExpr { left, op, right } = &predicate {
+                    if let Some(op) = inequality_operator(*op) {
+                        let (left, right) = (left.as_ref(), right.as_ref());
+                        match (columns.side(left)?, columns.side(right)?) {
+                            (Some(Side::Left), Some(Side::Right)) => {
+                                left_on.push(left.clone());
+                                right_on.push(columns.to_right_input(right.clone()));
+                                operators.push(op);
+                                continue;
+                            },
+                            (Some(Side::Right), Some(Side::Left)) => {
+                                left_on.push(right.clone());
+                                right_on.push(columns.to_right_input(left.clone()));
+                                operators.push(op.flip());
+                                continue;
+                            },
+                            _ => {},
+                        }
+                    }
+                }
+            }
+            remaining.push(predicate);
+        }
+
+        options.args.how = match operators[..] {
+            [] => JoinType::Cross,
+            [operator1] => JoinType::Range(RangeJoinOptions {
+                operator1,
+                operator2: None,
+            }),
+            [operator1, operator2] => JoinType::Range(RangeJoinOptions {
+                operator1,
+                operator2: Some(operator2),
+            }),
+            _ => unreachable!(),
+        };
+
+        let mut lp = self.join(other, left_on, right_on, Arc::new(options));
+        if let Some(predicate) = remaining.into_iter().reduce(|acc, e| acc.and(e)) {
+            lp = lp.filter(predicate);
+        }
+        Ok(lp)
+    }
+}
@@ -26,6 +26,8 @@ mod format;
 mod functions;
 pub(super) mod hive;
 pub(crate) mod iterator;
+#[cfg(feature = "range_join")]
+mod join_where;
 mod lit;
 pub(crate) mod optimizer;
 pub(crate) mod options;
@@ -89,7 +91,7 @@ pub enum DslPlan {
         predicate: Option<Expr>,
         file_options: FileScanOptions,
         scan_type: FileScan,
-        with_policy: Option<Arc<PathBuf>>,
+        with_policy: Option<Arc<ScanPolicy>>,
     },
     // we keep track of the projection and selection as it is cheaper to first project and then filter
     /// In memory DataFrame
@@ -0,0 +1,254 @@
+use super::*;
+
+/// The selectivity that is assumed for every comparison in a predicate.
+const FILTER_SELECTIVITY: f64 = 0.9;
+
+/// A relation that is inner joined onto a chain of joins.
+struct ChainInput {
+    node: Node,
+    left_on: Vec<ExprIR>,
+    right_on: Vec<ExprIR>,
+    options: Arc<JoinOptions>,
+}
+
+fn is_column(e: &ExprIR, expr_arena: &Arena<AExpr>) -> bool {
+    matches!(expr_arena.get(e.node()), AExpr::Column(name) if name.as_ref() == e.output_name())
+}
+
+/// Only inner joins on plain columns can be reordered. A slice or a validation would apply
+/// to another intermediate result once the join is moved.
+fn is_reorderable(
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    options: &JoinOptions,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let args = &options.args;
+    matches!(args.how, JoinType::Inner)
+        && args.slice.is_none()
+        && matches!(args.validation, JoinValidation::ManyToMany)
+        && left_on
+            .iter()
+            .chain(right_on)
+            .all(|e| is_column(e, expr_arena))
+}
+
+fn filtered(rows: f64, predicate: Option<&ExprIR>, expr_arena: &Arena<AExpr>) -> f64 {
+    match predicate {
+        Some(predicate) => {
+            let comparisons = expr_arena
+                .iter(predicate.node())
+                .filter(|(_, ae)| matches!(ae, AExpr::BinaryExpr { .. }))
+                .count()
+                + 1;
+            rows * FILTER_SELECTIVITY.powi(comparisons as i32)
+        },
+        None => rows,
+    }
+}
+
+/// Estimate the number of rows a node produces from the sizes of the scans below it. Unlike
+/// `set_estimated_row_counts` this does not modify the plan.
+fn estimate_rows(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> f64 {
+    use IR::*;
+    match lp_arena.get(node) {
+        DataFrameScan { df, selection, .. } => {
+            filtered(df.height() as f64, selection.as_ref(), expr_arena)
+        },
+        Scan {
+            file_info,
+            predicate,
+            ..
+        } => {
+            let (known_size, estimated_size) = file_info.row_estimation;
+            let rows = known_size.unwrap_or(estimated_size) as f64;
+            filtered(rows, predicate.as_ref(), expr_arena)
+        },
+        #[cfg(feature = "python")]
+        PythonScan { .. } => usize::MAX as f64,
+        Filter { input, predicate } => filtered(
+            estimate_rows(*input, lp_arena, expr_arena),
+            Some(predicate),
+            expr_arena,
+        ),
+        Slice { input, len, .. } => (*len as f64).min(estimate_rows(*input, lp_arena, expr_arena)),
+        Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } => {
+            let left = estimate_rows(*input_left, lp_arena, expr_arena);
+            let right = estimate_rows(*input_right, lp_arena, expr_arena);
+            let rows = match options.args.how {
+                JoinType::Left => left,
+                JoinType::Cross => left * right,
+                JoinType::Outer { .. } => left + right,
+                _ => left.max(right),
+            };
+            match options.args.slice {
+                Some((_, len)) => rows.min(len as f64),
+                None => rows,
+            }
+        },
+        Union { inputs, .. } => inputs
+            .iter()
+            .map(|input| estimate_rows(*input, lp_arena, expr_arena))
+            .sum(),
+        lp => lp
+            .get_inputs()
+            .into_iter()
+            .map(|input| estimate_rows(input, lp_arena, expr_arena))
+            .fold(0.0, f64::max),
+    }
+}
+
+/// Determine the order in which the relations of a chain are joined onto `base`. Every step
+/// takes the smallest relation of which the left keys are available. Returns `None` if the
+/// chain can not be reordered.
+fn join_order(
+    base: Node,
+    inputs: &[ChainInput],
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Vec<usize>> {
+    // The relation that provides every output column, where 0 is the base.
+    let mut providers = PlHashMap::new();
+    for name in lp_arena.get(base).schema(lp_arena).iter_names() {
+        providers.insert(name.to_string(), 0);
+    }
+    for (i, input) in inputs.iter().enumerate() {
+        let schema = lp_arena.get(input.node).schema(lp_arena);
+        for name in schema.iter_names() {
+            if input
+                .right_on
+                .iter()
+                .any(|e| e.output_name() == name.as_str())
+            {
+                continue;
+            }
+            // The suffix of a duplicate column depends on the join order.
+            if providers.insert(name.to_string(), i + 1).is_some() {
+                return None;
+            }
+        }
+    }
+
+    let dependencies = inputs
+        .iter()
+        .map(|input| {
+            input
+                .left_on
+                .iter()
+                .map(|e| providers.get(e.output_name()).copied())
+                .collect::<Option<Vec<usize>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let rows = inputs
+        .iter()
+        .map(|input| estimate_rows(input.node, lp_arena, expr_arena))
+        .collect::<Vec<_>>();
+
+    let mut joined = vec![false; inputs.len() + 1];
+    joined[0] = true;
+    let mut order = Vec::with_capacity(inputs.len());
+    while order.len() < inputs.len() {
+        // The written order is valid, so there is always a candidate.
+        let next = (0..inputs.len())
+            .filter(|&i| !joined[i + 1] && dependencies[i].iter().all(|&p| joined[p]))
+            .min_by(|&a, &b| rows[a].total_cmp(&rows[b]))?;
+        joined[next + 1] = true;
+        order.push(next);
+    }
+    Some(order)
+}
+
+/// Reorder the chain of inner joins that ends in `root`. Returns the inputs of the chain,
+/// which have to be visited next.
+fn reorder_chain(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    verbose: bool,
+) -> PolarsResult<Vec<Node>> {
+    let mut inputs = vec![];
+    let mut node = root;
+    while let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    {
+        if !is_reorderable(left_on, right_on, options, expr_arena) {
+            break;
+        }
+        inputs.push(ChainInput {
+            node: *input_right,
+            left_on: left_on.clone(),
+            right_on: right_on.clone(),
+            options: options.clone(),
+        });
+        node = *input_left;
+    }
+    if inputs.is_empty() {
+        return Ok(lp_arena.get(root).get_inputs());
+    }
+    let base = node;
+    inputs.reverse();
+
+    let mut visit = inputs.iter().map(|input| input.node).collect::<Vec<_>>();
+    visit.push(base);
+    if inputs.len() < 2 {
+        return Ok(visit);
+    }
+    let Some(order) = join_order(base, &inputs, lp_arena, expr_arena) else {
+        return Ok(visit);
+    };
+    if order.iter().enumerate().all(|(i, &j)| i == j) {
+        return Ok(visit);
+    }
+    if verbose {
+        eprintln!("reorder a chain of {} inner joins", inputs.len())
+    }
+
+    let schema = lp_arena.get(root).schema(lp_arena).into_owned();
+    let mut builder = IRBuilder::new(base, expr_arena, lp_arena);
+    for i in order {
+        let input = &inputs[i];
+        builder = builder.join(
+            input.node,
+            input.left_on.clone(),
+            input.right_on.clone(),
+            input.options.clone(),
+        );
+    }
+    // Restore the column order of the written joins.
+    if builder.schema().iter_names().ne(schema.iter_names()) {
+        builder = builder.project_simple(schema.iter_names().map(|name| name.as_str()))?;
+    }
+    let lp = builder.build();
+    lp_arena.replace(root, lp);
+    Ok(visit)
+}
+
+/// Reorder the chains of inner joins in the plan, so that the smallest relations are joined
+/// first and the intermediate results stay small. This does not maintain the row order.
+pub(super) fn reorder_joins(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    verbose: bool,
+) -> PolarsResult<()> {
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if matches!(lp_arena.get(node), IR::Join { .. }) {
+            stack.extend(reorder_chain(node, lp_arena, expr_arena, verbose)?);
+        } else {
+            lp_arena.get(node).copy_inputs(&mut stack);
+        }
+    }
+    Ok(())
+}
@@ -14,6 +14,7 @@ mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_order;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod simplify_expr;
@@ -73,17 +74,26 @@ pub fn optimize(
     let projection_pushdown = opt_state.projection_pushdown;
     let type_coercion = opt_state.type_coercion;
     let simplify_expr = opt_state.simplify_expr;
-    let slice_pushdown = opt_state.slice_pushdown;
+    // Only run the rewrites that keep the operators reported to the policy monitor.
+    let policy_checking = opt_state.policy_checking;
+    if verbose && policy_checking {
+        let skipped = opt_state.policy_skipped();
+        if !skipped.is_empty() {
+            eprintln!("policy checking is enabled; skip {}", skipped.join(", "))
+        }
+    }
+    let slice_pushdown = opt_state.slice_pushdown && !policy_checking;
     let streaming = opt_state.streaming;
     let fast_projection = opt_state.fast_projection;
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
     let eager = opt_state.eager;
+    let join_reorder = opt_state.join_reorder && !eager && !policy_checking;
     #[cfg(feature = "cse")]
-    let comm_subplan_elim = opt_state.comm_subplan_elim && !eager;
+    let comm_subplan_elim = opt_state.comm_subplan_elim && !eager && !policy_checking;
 
     #[cfg(feature = "cse")]
-    let comm_subexpr_elim = opt_state.comm_subexpr_elim;
+    let comm_subexpr_elim = opt_state.comm_subexpr_elim && !policy_checking;
     #[cfg(not(feature = "cse"))]
     let comm_subexpr_elim = false;
 
@@ -108,7 +118,9 @@ pub fn optimize(
     if simplify_expr {
         rules.push(Box::new(SimplifyExprRule {}));
         #[cfg(feature = "fused")]
-        rules.push(Box::new(fused::FusedArithmetic {}));
+        if !policy_checking {
+            rules.push(Box::new(fused::FusedArithmetic {}));
+        }
     }
 
     #[cfg(feature = "cse")]
@@ -147,12 +159,18 @@ pub fn optimize(
     }
 
     if predicate_pushdown {
-        let predicate_pushdown_opt = PredicatePushDown::new(hive_partition_eval);
+        let predicate_pushdown_opt =
+            PredicatePushDown::new(hive_partition_eval).policy_checking(policy_checking);
         let alp = lp_arena.take(lp_top);
         let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
         lp_arena.replace(lp_top, alp);
     }
 
+    // Run after the pushdowns, so that the estimates see the filtered scans.
+    if join_reorder {
+        join_order::reorder_joins(lp_top, lp_arena, expr_arena, verbose)?;
+    }
+
     // Make sure its before slice pushdown.
     if fast_projection {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(eager)));
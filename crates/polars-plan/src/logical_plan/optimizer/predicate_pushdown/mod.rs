@@ -23,6 +23,7 @@ pub struct PredicatePushDown<'a> {
     hive_partition_eval: HiveEval<'a>,
     verbose: bool,
     block_at_cache: bool,
+    policy_checking: bool,
 }
 
 impl<'a> PredicatePushDown<'a> {
@@ -31,6 +32,7 @@ impl<'a> PredicatePushDown<'a> {
             hive_partition_eval,
             verbose: verbose(),
             block_at_cache: true,
+            policy_checking: false,
         }
     }
 
@@ -39,6 +41,13 @@ impl<'a> PredicatePushDown<'a> {
         self
     }
 
+    /// Keep the predicates above the nodes that combine rows, so that the policy monitor is
+    /// told about the rows in the order the query was written.
+    pub(crate) fn policy_checking(mut self, toggle: bool) -> Self {
+        self.policy_checking = toggle;
+        self
+    }
+
     fn optional_apply_predicate(
         &self,
         lp: IR,
@@ -250,6 +259,11 @@ impl<'a> PredicatePushDown<'a> {
         use IR::*;
 
         match lp {
+            lp @ (Join { .. } | GroupBy { .. } | HConcat { .. } | Distinct { .. })
+                if self.policy_checking =>
+            {
+                self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
+            },
             Filter {
                 ref predicate,
                 input,
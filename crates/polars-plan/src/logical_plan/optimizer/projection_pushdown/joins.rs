@@ -257,8 +257,13 @@ pub(super) fn process_join(
             .unwrap();
             already_added_local_to_local_projected.insert(local_name);
         }
-        // In outer joins both columns remain. So `add_local=true` also for the right table
-        let add_local = matches!(options.args.how, JoinType::Outer { coalesce: false });
+        // In outer and range joins both columns remain. So `add_local=true` also for the right table
+        let add_local = match options.args.how {
+            JoinType::Outer { coalesce: false } => true,
+            #[cfg(feature = "range_join")]
+            JoinType::Range(_) => true,
+            _ => false,
+        };
         for e in &right_on {
             // In case of outer joins we also add the columns.
             // But before we do that we must check if the column wasn't already added by the lhs.
@@ -1,6 +1,8 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
 #[cfg(feature = "csv")]
 use std::num::NonZeroUsize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 #[cfg(feature = "csv")]
@@ -133,6 +135,76 @@ pub struct FileScanOptions {
     pub hive_options: HiveOptions,
 }
 
+/// Where the policies of the scanned files are read from when policy checking is enabled.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScanPolicy {
+    /// A single policy file covering the rows of all scanned files, in order.
+    File(PathBuf),
+    /// A policy file next to every data file, named after it with the given extension, e.g.
+    /// `data/a.parquet` is covered by `data/a.policy` for the extension `policy`.
+    Sibling { extension: String },
+    /// A policy file per hive partition. Every `{key}` in the template is replaced by the value
+    /// of the hive partition `key` of the data file, e.g. `policies/year={year}.policy`.
+    HiveTemplate(String),
+}
+
+impl ScanPolicy {
+    /// Whether the policy file depends on the data file that is scanned.
+    pub fn is_per_file(&self) -> bool {
+        !matches!(self, ScanPolicy::File(_))
+    }
+
+    /// The policy file covering the rows of the data file `path`.
+    pub fn resolve(&self, path: &Path) -> PolarsResult<Cow<'_, Path>> {
+        match self {
+            ScanPolicy::File(policy) => Ok(Cow::Borrowed(policy)),
+            ScanPolicy::Sibling { extension } => Ok(Cow::Owned(path.with_extension(extension))),
+            ScanPolicy::HiveTemplate(template) => {
+                let mut out = String::with_capacity(template.len());
+                let mut rest = template.as_str();
+                while let Some(start) = rest.find('{') {
+                    out.push_str(&rest[..start]);
+                    let len = rest[start..].find('}').ok_or_else(
+                        || polars_err!(ComputeError: "unclosed '{{' in policy template '{}'", template),
+                    )?;
+                    let key = &rest[start + 1..start + len];
+                    let value = path
+                        .components()
+                        .filter_map(|c| c.as_os_str().to_str()?.split_once('='))
+                        .find_map(|(k, v)| (k == key).then_some(v))
+                        .ok_or_else(|| {
+                            polars_err!(
+                                ComputeError: "hive partition '{}' of policy template '{}' not found in '{}'",
+                                key, template, path.display()
+                            )
+                        })?;
+                    out.push_str(value);
+                    rest = &rest[start + len + 1..];
+                }
+                out.push_str(rest);
+                Ok(Cow::Owned(out.into()))
+            },
+        }
+    }
+}
+
+impl From<PathBuf> for ScanPolicy {
+    fn from(path: PathBuf) -> Self {
+        ScanPolicy::File(path)
+    }
+}
+
+impl Display for ScanPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScanPolicy::File(path) => write!(f, "{}", path.display()),
+            ScanPolicy::Sibling { extension } => write!(f, "<data file>.{extension}"),
+            ScanPolicy::HiveTemplate(template) => write!(f, "{template}"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UnionOptions {
@@ -374,3 +446,34 @@ impl Default for ProjectionOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scan_policy_resolve() -> PolarsResult<()> {
+        let path = Path::new("data/year=2024/month=1/0.parquet");
+
+        let policy = ScanPolicy::File("all.policy".into());
+        assert_eq!(policy.resolve(path)?, Path::new("all.policy"));
+
+        let policy = ScanPolicy::Sibling {
+            extension: "policy".into(),
+        };
+        assert_eq!(
+            policy.resolve(path)?,
+            Path::new("data/year=2024/month=1/0.policy")
+        );
+
+        let policy = ScanPolicy::HiveTemplate("policies/{year}-{month}.policy".into());
+        assert_eq!(policy.resolve(path)?, Path::new("policies/2024-1.policy"));
+
+        let policy = ScanPolicy::HiveTemplate("policies/{day}.policy".into());
+        assert!(policy.resolve(path).is_err());
+        let policy = ScanPolicy::HiveTemplate("policies/{year.policy".into());
+        assert!(policy.resolve(path).is_err());
+
+        Ok(())
+    }
+}
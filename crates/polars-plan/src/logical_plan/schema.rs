@@ -281,6 +281,20 @@ pub(crate) fn det_join_schema(
         // the schema will never change.
         #[cfg(feature = "semi_anti_join")]
         JoinType::Semi | JoinType::Anti => Ok(schema_left.clone()),
+        // a range join keeps all columns of both sides, like a cross join
+        #[cfg(feature = "range_join")]
+        JoinType::Range(_) => {
+            let mut new_schema = (**schema_left).clone();
+            for (name, dtype) in schema_right.iter() {
+                if schema_left.contains(name.as_str()) {
+                    let new_name = format_smartstring!("{}{}", name, options.args.suffix());
+                    new_schema.with_column(new_name, dtype.clone());
+                } else {
+                    new_schema.with_column(name.clone(), dtype.clone());
+                }
+            }
+            Ok(Arc::new(new_schema))
+        },
         _ => {
             let mut new_schema = Schema::with_capacity(schema_left.len() + schema_right.len());
 
@@ -6,18 +6,23 @@ use polars_error::to_compute_err;
 use polars_lazy::prelude::*;
 use polars_plan::prelude::*;
 use sqlparser::ast::{
-    Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg, GroupByExpr, JoinOperator,
+    Cte, Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg, GroupByExpr, JoinOperator,
     ObjectName, ObjectType, Offset, OrderByExpr, Query, Select, SelectItem, SetExpr, SetOperator,
     SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, Value as SQLValue,
     WildcardAdditionalOptions,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
+use uuid::Uuid;
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
 use crate::sql_expr::{parse_sql_expr, process_join};
 use crate::table_functions::PolarsTableFunctions;
 
+/// The column that numbers the occurrences of equal rows in `INTERSECT ALL` and `EXCEPT ALL`.
+#[cfg(feature = "semi_anti_join")]
+const SET_OCCURRENCE_COLUMN: &str = "__POLARS_SQL_SET_OCCURRENCE";
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
@@ -25,6 +30,9 @@ pub struct SQLContext {
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     aliases: RefCell<PlHashMap<String, String>>,
+    policy_checking_enabled: bool,
+    ctx_id: Uuid,
+    max_recursive_iterations: usize,
 }
 
 impl Default for SQLContext {
@@ -34,6 +42,9 @@ impl Default for SQLContext {
             table_map: Default::default(),
             cte_map: Default::default(),
             aliases: Default::default(),
+            policy_checking_enabled: false,
+            ctx_id: Uuid::nil(),
+            max_recursive_iterations: 1000,
         }
     }
 }
@@ -76,6 +87,11 @@ impl SQLContext {
         self.table_map.insert(name.to_owned(), lf);
     }
 
+    /// Register a [`DataFrame`] protected by `policy` as a table in the SQLContext.
+    pub fn register_with_policy(&mut self, name: &str, df: DataFrame, policy: DataFramePolicy) {
+        self.register(name, LazyFrame::from_df_with_policy(df, policy));
+    }
+
     /// Unregister a [`LazyFrame`] table from the [`SQLContext`].
     pub fn unregister(&mut self, name: &str) {
         self.table_map.remove(&name.to_owned());
@@ -112,13 +128,35 @@ impl SQLContext {
             .parse_statements()
             .map_err(to_compute_err)?;
         polars_ensure!(ast.len() == 1, ComputeError: "One and only one statement at a time please");
-        let res = self.execute_statement(ast.first().unwrap());
+        let res = self
+            .execute_statement(ast.first().unwrap())
+            .map(|lf| self.with_policy_settings(lf));
         // Every execution should clear the CTE map.
         self.cte_map.borrow_mut().clear();
         self.aliases.borrow_mut().clear();
         res
     }
 
+    /// Enable policy checking for the [`LazyFrame`]s returned by [`SQLContext::execute`].
+    pub fn set_policy_checking(mut self, enabled: bool) -> Self {
+        self.policy_checking_enabled = enabled;
+        self
+    }
+
+    /// Set the monitor context the [`LazyFrame`]s returned by [`SQLContext::execute`] are
+    /// checked in.
+    pub fn set_ctx_id(mut self, ctx_id: Uuid) -> Self {
+        self.ctx_id = ctx_id;
+        self
+    }
+
+    /// Set the number of iterations after which the evaluation of a recursive CTE that did not
+    /// reach a fixpoint fails. Defaults to 1000.
+    pub fn set_max_recursive_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_recursive_iterations = max_iterations;
+        self
+    }
+
     /// add a function registry to the SQLContext
     /// the registry provides the ability to add custom functions to the SQLContext
     pub fn with_function_registry(mut self, function_registry: Arc<dyn FunctionRegistry>) -> Self {
@@ -138,6 +176,14 @@ impl SQLContext {
 }
 
 impl SQLContext {
+    fn with_policy_settings(&self, lf: LazyFrame) -> LazyFrame {
+        if self.policy_checking_enabled {
+            lf.set_ctx_id(self.ctx_id).set_policy_checking(true)
+        } else {
+            lf
+        }
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
@@ -147,10 +193,12 @@ impl SQLContext {
         table_name
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.aliases.borrow().get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .cloned()
+                        .or_else(|| self.cte_map.borrow().get(alias).cloned())
+                })
             })
     }
 
@@ -194,6 +242,14 @@ impl SQLContext {
                 left,
                 right,
             } => self.process_union(left, right, set_quantifier, query),
+            #[cfg(feature = "semi_anti_join")]
+            SetExpr::SetOperation {
+                op: op @ (SetOperator::Intersect | SetOperator::Except),
+                set_quantifier,
+                left,
+                right,
+            } => self.process_except_intersect(left, right, op, set_quantifier, query),
+            #[allow(unreachable_patterns)]
             SetExpr::SetOperation { op, .. } => {
                 polars_bail!(InvalidOperation: "'{}' operation not yet supported", op)
             },
@@ -237,6 +293,89 @@ impl SQLContext {
         }
     }
 
+    /// INTERSECT and EXCEPT are semi and anti joins on all columns, in which nulls are equal.
+    /// The ALL forms number the occurrences of every row on both sides and join on that number
+    /// as well. The n-th occurrence of a row then only has a match if the other side has at
+    /// least n of them, which leaves `min(m, n)` rows for INTERSECT and `max(m - n, 0)` rows
+    /// for EXCEPT.
+    #[cfg(feature = "semi_anti_join")]
+    fn process_except_intersect(
+        &mut self,
+        left: &SetExpr,
+        right: &SetExpr,
+        op: &SetOperator,
+        quantifier: &SetQuantifier,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
+        let left = self.process_set_expr(left, query)?;
+        let right = self.process_set_expr(right, query)?;
+        let left_schema = left.schema()?;
+        let right_schema = right.schema()?;
+        polars_ensure!(
+            left_schema.len() == right_schema.len(),
+            InvalidOperation: "'{} {}' requires the same number of columns on both sides, got {} and {}",
+            op, quantifier, left_schema.len(), right_schema.len()
+        );
+        let (all, by_name) = match quantifier {
+            SetQuantifier::All => (true, false),
+            SetQuantifier::Distinct | SetQuantifier::None => (false, false),
+            SetQuantifier::AllByName => (true, true),
+            SetQuantifier::ByName | SetQuantifier::DistinctByName => (false, true),
+        };
+        let names = left_schema.iter_names().collect::<Vec<_>>();
+        // Line up the columns of the right side with those of the left side.
+        let right_columns = if by_name {
+            names
+                .iter()
+                .map(|name| {
+                    polars_ensure!(
+                        right_schema.contains(name),
+                        ColumnNotFound: "column '{}' of '{} {}' is missing on the right side",
+                        name, op, quantifier
+                    );
+                    Ok(col(name))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?
+        } else {
+            right_schema
+                .iter_names()
+                .zip(&names)
+                .map(|(name, left_name)| col(name).alias(left_name))
+                .collect()
+        };
+        let right = right.select(right_columns);
+
+        let how = match op {
+            SetOperator::Intersect => JoinType::Semi,
+            _ => JoinType::Anti,
+        };
+        let mut keys = names.iter().map(|name| col(name)).collect::<Vec<_>>();
+        let join = |left: LazyFrame, right: LazyFrame, keys: Vec<Expr>| {
+            left.join_builder()
+                .with(right)
+                .left_on(keys.clone())
+                .right_on(keys)
+                .how(how)
+                .join_nulls(true)
+                .finish()
+        };
+        if all {
+            let number_occurrences = |lf: LazyFrame| {
+                lf.with_row_index(SET_OCCURRENCE_COLUMN, None)
+                    .with_column(col(SET_OCCURRENCE_COLUMN).cum_count(false).over(&keys))
+            };
+            let (left, right) = (number_occurrences(left), number_occurrences(right));
+            keys.push(col(SET_OCCURRENCE_COLUMN));
+            Ok(join(left, right, keys).drop([SET_OCCURRENCE_COLUMN]))
+        } else {
+            Ok(join(
+                left.unique(None, UniqueKeepStrategy::Any),
+                right,
+                keys,
+            ))
+        }
+    }
+
     // EXPLAIN SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
@@ -299,18 +438,107 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(ComputeError: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let cte_lf = self.execute_query(&cte.query)?;
+                let cte_lf = match &*cte.query.body {
+                    SetExpr::SetOperation {
+                        op: SetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    } if with.recursive && references_table(right, &cte_name) => {
+                        self.execute_recursive_cte(cte, left, right, set_quantifier)?
+                    },
+                    _ => rename_cte_columns(self.execute_query(&cte.query)?, &cte.alias)?,
+                };
                 self.register_cte(&cte_name, cte_lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate `anchor UNION [ALL] recursive` until a fixpoint is reached. In every iteration
+    /// the recursive term reads the rows that were added by the previous one. With `UNION`
+    /// the rows that were already produced are not added again, so that cycles end.
+    fn execute_recursive_cte(
+        &mut self,
+        cte: &Cte,
+        anchor: &SetExpr,
+        recursive: &SetExpr,
+        quantifier: &SetQuantifier,
+    ) -> PolarsResult<LazyFrame> {
+        // The intermediate results are collected, which would drop the policies of the inputs.
+        polars_ensure!(
+            !self.policy_checking_enabled,
+            InvalidOperation: "recursive CTEs are not supported with policy checking"
+        );
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                InvalidOperation: "'UNION {}' is not supported in recursive CTEs", quantifier
+            ),
+        };
+        let name = &cte.alias.name.value;
+        let query = &cte.query;
+        self.register_ctes(query)?;
+
+        let anchor = rename_cte_columns(self.process_set_expr(anchor, query)?, &cte.alias)?;
+        let mut result = if distinct {
+            anchor.unique_stable(None, UniqueKeepStrategy::First)
+        } else {
+            anchor
+        }
+        .collect()?;
+        let schema = result.schema();
+        let args = UnionArgs {
+            rechunk: false,
+            parallel: true,
+            ..Default::default()
+        };
+
+        let mut working = result.clone();
+        let mut iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                iterations < self.max_recursive_iterations,
+                ComputeError: "recursive CTE '{}' did not reach a fixpoint within {} iterations",
+                name, self.max_recursive_iterations
+            );
+            iterations += 1;
+
+            self.register_cte(name, working.lazy());
+            let step = self.process_set_expr(recursive, query)?;
+            let step_schema = step.schema()?;
+            polars_ensure!(
+                step_schema.len() == schema.len(),
+                ComputeError: "the recursive term of CTE '{}' has {} columns, expected {}",
+                name, step_schema.len(), schema.len()
+            );
+            let step = step.select(
+                step_schema
+                    .iter_names()
+                    .zip(schema.iter())
+                    .map(|(step_name, (name, dtype))| {
+                        col(step_name).strict_cast(dtype.clone()).alias(name)
+                    })
+                    .collect::<Vec<_>>(),
+            );
+
+            let height = result.height();
+            let union = polars_lazy::dsl::concat([result.lazy(), step], args)?;
+            result = if distinct {
+                union.unique_stable(None, UniqueKeepStrategy::First)
+            } else {
+                union
+            }
+            .collect()?;
+            // The rows that were not produced before.
+            working = result.slice(height as i64, usize::MAX);
+        }
+        Ok(result.lazy())
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
@@ -834,6 +1062,46 @@ impl SQLContext {
     }
 }
 
+/// Rename the columns of a CTE to the names given in `WITH name(a, b, ...)`.
+fn rename_cte_columns(lf: LazyFrame, alias: &TableAlias) -> PolarsResult<LazyFrame> {
+    if alias.columns.is_empty() {
+        return Ok(lf);
+    }
+    let schema = lf.schema()?;
+    polars_ensure!(
+        schema.len() == alias.columns.len(),
+        ComputeError: "CTE '{}' has {} columns, but {} column names were given",
+        alias.name.value, schema.len(), alias.columns.len()
+    );
+    let columns = schema
+        .iter_names()
+        .zip(&alias.columns)
+        .map(|(name, alias)| col(name).alias(&alias.value))
+        .collect::<Vec<_>>();
+    Ok(lf.select(columns))
+}
+
+/// Whether `expr` reads from the table `name` in a FROM or JOIN clause.
+fn references_table(expr: &SetExpr, name: &str) -> bool {
+    let in_relation = |relation: &TableFactor| match relation {
+        TableFactor::Table { name: table, .. } => {
+            table.0.first().is_some_and(|ident| ident.value == name)
+        },
+        TableFactor::Derived { subquery, .. } => references_table(&subquery.body, name),
+        _ => false,
+    };
+    match expr {
+        SetExpr::Select(select) => select.from.iter().any(|tbl| {
+            in_relation(&tbl.relation) || tbl.joins.iter().any(|join| in_relation(&join.relation))
+        }),
+        SetExpr::Query(query) => references_table(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            references_table(left, name) || references_table(right, name)
+        },
+        _ => false,
+    }
+}
+
 impl SQLContext {
     /// Get internal table map. For internal use only.
     pub fn get_table_map(&self) -> PlHashMap<String, LazyFrame> {
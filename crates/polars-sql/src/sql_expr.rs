@@ -814,6 +814,18 @@ pub(super) fn process_join(
     join_tbl_name: &str,
     join_type: JoinType,
 ) -> PolarsResult<LazyFrame> {
+    #[cfg(feature = "range_join")]
+    if let JoinConstraint::On(expression) = constraint {
+        if has_inequality(expression) {
+            polars_ensure!(
+                matches!(join_type, JoinType::Inner),
+                InvalidOperation: "SQL join clauses only support inequality constraints in INNER joins; found a {} join", join_type
+            );
+            let schema_left = left_tbl.schema()?;
+            let predicates = process_join_where(expression, &schema_left, tbl_name, join_tbl_name)?;
+            return left_tbl.join_where(right_tbl, predicates);
+        }
+    }
     let (left_on, right_on) = process_join_constraint(constraint, tbl_name, join_tbl_name)?;
 
     Ok(left_tbl
@@ -881,6 +893,102 @@ fn process_join_on(
     }
 }
 
+/// Whether a join constraint compares the tables with an inequality.
+#[cfg(feature = "range_join")]
+fn has_inequality(expression: &SQLExpr) -> bool {
+    match expression {
+        SQLExpr::BinaryOp { left, op, right } => match op {
+            BinaryOperator::Lt
+            | BinaryOperator::LtEq
+            | BinaryOperator::Gt
+            | BinaryOperator::GtEq => true,
+            BinaryOperator::And => has_inequality(left) || has_inequality(right),
+            _ => false,
+        },
+        SQLExpr::Nested(expr) => has_inequality(expr),
+        _ => false,
+    }
+}
+
+/// A column in a join constraint, by its name after the join: a column of the right table that
+/// is also in the left table gets the default suffix.
+#[cfg(feature = "range_join")]
+fn process_join_where_operand(
+    expression: &SQLExpr,
+    schema_left: &Schema,
+    left_name: &str,
+    right_name: &str,
+) -> PolarsResult<Expr> {
+    match expression {
+        SQLExpr::CompoundIdentifier(idents) if idents.len() == 2 => {
+            let (tbl, column) = (&idents[0].value, &idents[1].value);
+            if tbl == left_name {
+                Ok(col(column))
+            } else if tbl == right_name {
+                if schema_left.contains(column) {
+                    Ok(col(&format!("{column}_right")))
+                } else {
+                    Ok(col(column))
+                }
+            } else {
+                polars_bail!(InvalidOperation: "SQL join clauses can only refer to the tables '{}' and '{}'; found '{}'", left_name, right_name, tbl)
+            }
+        },
+        SQLExpr::Identifier(ident) => Ok(col(&ident.value)),
+        SQLExpr::Nested(expr) => {
+            process_join_where_operand(expr, schema_left, left_name, right_name)
+        },
+        _ => {
+            polars_bail!(InvalidOperation: "SQL join clauses support constraints on identifiers; found {:?}", expression)
+        },
+    }
+}
+
+/// The predicates of a join constraint with inequalities, for [`LazyFrame::join_where`].
+#[cfg(feature = "range_join")]
+fn process_join_where(
+    expression: &SQLExpr,
+    schema_left: &Schema,
+    left_name: &str,
+    right_name: &str,
+) -> PolarsResult<Vec<Expr>> {
+    match expression {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut predicates = process_join_where(left, schema_left, left_name, right_name)?;
+            predicates.extend(process_join_where(
+                right,
+                schema_left,
+                left_name,
+                right_name,
+            )?);
+            Ok(predicates)
+        },
+        SQLExpr::BinaryOp { left, op, right } => {
+            let left = process_join_where_operand(left, schema_left, left_name, right_name)?;
+            let right = process_join_where_operand(right, schema_left, left_name, right_name)?;
+            Ok(vec![match op {
+                BinaryOperator::Eq => left.eq(right),
+                BinaryOperator::NotEq => left.neq(right),
+                BinaryOperator::Lt => left.lt(right),
+                BinaryOperator::LtEq => left.lt_eq(right),
+                BinaryOperator::Gt => left.gt(right),
+                BinaryOperator::GtEq => left.gt_eq(right),
+                _ => {
+                    polars_bail!(InvalidOperation: "SQL join clauses support comparisons combined with 'AND'; found op = '{:?}'", op)
+                },
+            }])
+        },
+        SQLExpr::Nested(expr) => process_join_where(expr, schema_left, left_name, right_name),
+        _ => {
+            polars_bail!(InvalidOperation: "SQL join clauses support comparisons combined with 'AND'; found expression = {:?}", expression)
+        },
+    }
+}
+
 pub(super) fn process_join_constraint(
     constraint: &JoinConstraint,
     left_name: &str,
@@ -22,9 +22,10 @@ pub(crate) enum PolarsTableFunctions {
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
-    /// SQL 'read_parquet' function
+    /// SQL 'read_parquet' function, optionally with the policy of the file
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('path/to/file.parquet', policy => 'path/to/file.policy')
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
@@ -91,8 +92,26 @@ impl PolarsTableFunctions {
     fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
         polars_ensure!(!args.is_empty(), ComputeError: "read_parquet expected a path");
 
+        use polars_lazy::prelude::{ScanArgsParquet, ScanPolicy};
+        use sqlparser::ast::{Expr as SQLExpr, Value as SQLValue};
+
         let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+        let mut scan_args = ScanArgsParquet::default();
+        for arg in &args[1..] {
+            match arg {
+                FunctionArg::Named {
+                    name,
+                    arg: FunctionArgExpr::Expr(SQLExpr::Value(SQLValue::SingleQuotedString(s))),
+                } if name.value == "policy" => {
+                    scan_args.with_policy = Some(ScanPolicy::File(s.into()))
+                },
+                _ => polars_bail!(
+                    ComputeError:
+                    "read_parquet only accepts a `policy => 'path'` argument after the path; received: {}", arg,
+                ),
+            }
+        }
+        let lf = LazyFrame::scan_parquet(&path, scan_args)?;
         Ok((path, lf))
     }
 
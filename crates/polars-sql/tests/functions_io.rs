@@ -87,6 +87,22 @@ fn read_parquet_tbl() {
     assert_eq!(df_2.width(), 4);
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn read_parquet_tbl_policy() {
+    let mut context = SQLContext::new();
+    let sql = r#"
+            SELECT *
+            FROM read_parquet('../../examples/datasets/foods1.parquet', policy => 'foods1.policy')"#;
+    let explained = context.execute(sql).unwrap().explain_policy().unwrap();
+    assert!(explained.contains("policy: foods1.policy"));
+
+    let sql = r#"
+            SELECT *
+            FROM read_parquet('../../examples/datasets/foods1.parquet', n_rows => '1')"#;
+    assert!(context.execute(sql).is_err());
+}
+
 #[test]
 #[cfg(feature = "ipc")]
 fn read_ipc_tbl() {
@@ -161,6 +161,120 @@ fn test_union_all() {
     assert!(actual.equals(&expected));
 }
 
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_intersect_except() {
+    let df1 = df![
+        "a" => [Some(1), Some(1), Some(1), Some(2), None, None, Some(3)],
+        "b" => [Some("x"), Some("x"), Some("x"), Some("y"), None, None, Some("z")],
+    ]
+    .unwrap();
+    // The columns are matched by position.
+    let df2 = df![
+        "c" => [Some(1), None, Some(1), Some(4)],
+        "d" => [Some("x"), None, Some("x"), Some("w")],
+    ]
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("test", df1.lazy());
+    ctx.register("test2", df2.lazy());
+
+    let cases = [
+        (
+            "INTERSECT",
+            df![
+                "a" => [None, Some(1)],
+                "b" => [None, Some("x")],
+            ],
+        ),
+        (
+            "INTERSECT ALL",
+            df![
+                "a" => [None, Some(1), Some(1)],
+                "b" => [None, Some("x"), Some("x")],
+            ],
+        ),
+        (
+            "EXCEPT",
+            df![
+                "a" => [2, 3],
+                "b" => ["y", "z"],
+            ],
+        ),
+        (
+            "EXCEPT ALL",
+            df![
+                "a" => [None, Some(1), Some(2), Some(3)],
+                "b" => [None, Some("x"), Some("y"), Some("z")],
+            ],
+        ),
+    ];
+    for (op, expected) in cases {
+        let sql = format!("SELECT * FROM test {op} SELECT * FROM test2");
+        let actual = ctx
+            .execute(&sql)
+            .unwrap()
+            .sort(["a", "b"], Default::default())
+            .collect()
+            .unwrap();
+        assert!(actual.equals_missing(&expected.unwrap()), "{op}: {actual}");
+    }
+}
+
+#[test]
+fn test_recursive_cte() {
+    let employees = df![
+        "id" => [1, 2, 3, 4, 5, 6],
+        "manager_id" => [None, Some(1), Some(1), Some(2), Some(2), Some(4)],
+    ]
+    .unwrap();
+    let edges = df![
+        "src" => [1, 2, 3],
+        "dst" => [2, 3, 1],
+    ]
+    .unwrap();
+    let mut ctx = SQLContext::new().set_max_recursive_iterations(10);
+    ctx.register("employees", employees.lazy());
+    ctx.register("edges", edges.lazy());
+
+    let sql = r#"
+    WITH RECURSIVE chain(emp_id, depth) AS (
+        SELECT id, 0 AS depth FROM employees WHERE manager_id IS NULL
+        UNION ALL
+        SELECT e.id, c.depth + 1 FROM employees e JOIN chain c ON e.manager_id = c.emp_id
+    )
+    SELECT * FROM chain ORDER BY emp_id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df![
+        "emp_id" => [1, 2, 3, 4, 5, 6],
+        "depth" => [0i64, 1, 1, 2, 2, 3],
+    ]
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    // UNION does not add the rows that were already found, so the cycle ends.
+    let sql = |quantifier: &str| {
+        format!(
+            r#"
+            WITH RECURSIVE reach(node) AS (
+                SELECT src FROM edges WHERE src = 1
+                UNION {quantifier}
+                SELECT e.dst FROM edges e JOIN reach r ON e.src = r.node
+            )
+            SELECT * FROM reach ORDER BY node
+            "#
+        )
+    };
+    let actual = ctx.execute(&sql("")).unwrap().collect().unwrap();
+    let expected = df!["node" => [1, 2, 3]].unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    // UNION ALL keeps following the cycle until the iteration cap.
+    assert!(ctx.execute(&sql("ALL")).is_err());
+}
+
 #[test]
 fn test_drop_table() {
     let mut ctx = create_ctx();
@@ -422,3 +536,100 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     ctx.execute(sql).unwrap().collect().unwrap();
 }
+
+#[test]
+#[cfg(feature = "range_join")]
+fn test_join_on_inequality() {
+    let intervals = df! {
+        "id" => [0, 1, 2],
+        "lo" => [1, 4, 10],
+        "hi" => [5, 8, 11],
+    }
+    .unwrap();
+    let events = df! {
+        "id" => [0, 1, 2, 3, 4],
+        "ts" => [6, 2, 20, 4, 7],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("intervals", intervals.lazy());
+    ctx.register("events", events.lazy());
+
+    let sql = r#"
+        SELECT * FROM intervals
+            INNER JOIN events ON
+                intervals.lo <= events.ts AND
+                events.ts < intervals.hi AND
+                intervals.id <> events.id
+    "#;
+    let lf = ctx.execute(sql).unwrap();
+    assert!(lf.describe_plan().contains("RANGE JOIN"));
+    let actual = lf.collect().unwrap();
+
+    let expected = df! {
+        "id" => [0, 0, 1, 1, 1],
+        "lo" => [1, 1, 4, 4, 4],
+        "hi" => [5, 5, 8, 8, 8],
+        "id_right" => [1, 3, 0, 3, 4],
+        "ts" => [2, 4, 6, 4, 7],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {:?}\nactual={:?}",
+        expected,
+        actual
+    );
+
+    // Inequalities are only supported in inner joins.
+    let sql = "SELECT * FROM intervals LEFT JOIN events ON intervals.lo <= events.ts";
+    assert!(ctx.execute(sql).is_err());
+}
+
+/// A policy for a frame of `height` rows with the single column `protected`, whose cells may only
+/// leave a query summed up.
+fn sum_only_policy(protected: &str, height: usize) -> DataFramePolicy {
+    let policy =
+        r#"{"PolicyDeclassify":{"label":{"PolicyAgg":{"ops":["Sum"]}},"next":"PolicyClean"}}"#;
+    let json = format!(
+        r#"{{"schema":["{protected}"],"columns":[{{"policies":[{}]}}]}}"#,
+        vec![policy; height].join(",")
+    );
+    let policy = picachv::PolicyGuardedDataFrame::from_json(&json).unwrap();
+    DataFramePolicy::Bin(Arc::from(policy.to_bytes()))
+}
+
+#[test]
+fn test_policy_checking_context() {
+    let df = df! {
+        "a" => [1, 2, 3],
+    }
+    .unwrap();
+    let policy = sum_only_policy("a", 3);
+
+    // The monitor is shared by all tests and may already be initialized.
+    let _ = picachv::native::init_monitor();
+    let checked = |sql: &str| {
+        let mut ctx = SQLContext::new()
+            .set_policy_checking(true)
+            .set_ctx_id(picachv::native::open_new().unwrap());
+        ctx.register_with_policy("df", df.clone(), policy.clone());
+        ctx.execute(sql).unwrap().collect()
+    };
+
+    // `a` may only leave the query summed up.
+    let out = checked("SELECT SUM(a) AS a FROM df WHERE a > 1").unwrap();
+    assert_eq!(out, df! { "a" => [5] }.unwrap());
+    let res = checked("SELECT a FROM df WHERE a > 1");
+    assert!(matches!(res, Err(PolarsError::PolicyViolation(_))));
+
+    // Without policy checking the policy is ignored.
+    let mut ctx = SQLContext::new();
+    ctx.register_with_policy("df", df, policy);
+    let out = ctx
+        .execute("SELECT a FROM df WHERE a > 1")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(out.height(), 2);
+}
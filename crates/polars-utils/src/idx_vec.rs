@@ -177,16 +177,17 @@ impl<T> Drop for UnitVec<T> {
 
 impl<T> Clone for UnitVec<T> {
     fn clone(&self) -> Self {
+        // A single element must be stored inline, as a capacity of 1 means the data pointer holds
+        // the element itself.
+        let mut new = Self::new();
+        if self.len > 1 {
+            new.realloc(self.len);
+        }
         unsafe {
-            let mut me = std::mem::ManuallyDrop::new(Vec::with_capacity(self.len));
-            let buffer = me.as_mut_ptr();
-            std::ptr::copy(self.data_ptr(), buffer, self.len);
-            UnitVec {
-                data: buffer,
-                len: self.len,
-                capacity: NonZeroUsize::new(std::cmp::max(self.len, 1)).unwrap(),
-            }
+            std::ptr::copy_nonoverlapping(self.data_ptr(), new.data_ptr_mut(), self.len);
         }
+        new.len = self.len;
+        new
     }
 }
 
@@ -303,3 +304,22 @@ macro_rules! unitvec {
             vec![$($x),+].into()
     );
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clone() {
+        for len in 0..10 {
+            let v = (0..len as IdxSize).collect::<Vec<_>>();
+            let unit_vec = UnitVec::from(v.clone());
+            assert_eq!(unit_vec.clone().as_slice(), v.as_slice());
+        }
+
+        // A vector that spilled to the heap and shrunk back to a single element.
+        let mut unit_vec: IdxVec = unitvec![1, 2];
+        unit_vec.pop();
+        assert_eq!(unit_vec.clone().as_slice(), &[1]);
+    }
+}
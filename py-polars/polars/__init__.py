@@ -86,6 +86,7 @@ from polars.exceptions import (
     PolarsError,
     PolarsPanicError,
     PolarsWarning,
+    PolicyViolationError,
     SchemaError,
     SchemaFieldNotFoundError,
     ShapeError,
@@ -212,6 +213,7 @@ from polars.meta import (
     thread_pool_size,
     threadpool_size,
 )
+from polars.policy import init_monitor, open_new
 from polars.series import Series
 from polars.sql import SQLContext
 from polars.string_cache import (
@@ -239,6 +241,7 @@ __all__ = [
     "OutOfBoundsError",
     "PolarsError",
     "PolarsPanicError",
+    "PolicyViolationError",
     "SchemaError",
     "SchemaFieldNotFoundError",
     "ShapeError",
@@ -324,6 +327,9 @@ __all__ = [
     "disable_string_cache",
     "enable_string_cache",
     "using_string_cache",
+    # polars.policy
+    "init_monitor",
+    "open_new",
     # polars.config
     "Config",
     # polars.functions.whenthen
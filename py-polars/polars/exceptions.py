@@ -11,6 +11,7 @@ try:
         PolarsError,
         PolarsPanicError,
         PolarsWarning,
+        PolicyViolationError,
         SchemaError,
         SchemaFieldNotFoundError,
         ShapeError,
@@ -68,6 +69,20 @@ except ImportError:
     class PolarsPanicError(PolarsError):  # type: ignore[no-redef, misc]
         """Exception raised when an unexpected state causes a panic in the underlying Rust library."""  # noqa: W505
 
+    class PolicyViolationError(PolarsError):  # type: ignore[no-redef, misc]
+        """
+        Exception raised when the privacy monitor rejects a policy-checked query.
+
+        Attributes
+        ----------
+        clause
+            The reason given by the monitor, i.e. the policy clause that is violated.
+        operator
+            The operator of the query that was rejected, or `None` if unknown.
+        column
+            The column the rejected operator worked on, or `None` if unknown.
+        """
+
     class SchemaError(PolarsError):  # type: ignore[no-redef, misc]
         """Exception raised when an unexpected schema mismatch causes an error."""
 
@@ -170,6 +185,7 @@ __all__ = [
     "PolarsError",
     "PolarsPanicError",
     "PolarsWarning",
+    "PolicyViolationError",
     "RowsError",
     "SchemaError",
     "SchemaFieldNotFoundError",
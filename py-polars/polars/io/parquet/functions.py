@@ -301,6 +301,7 @@ def scan_parquet(
     cache: bool = True,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
+    policy: str | Path | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -359,6 +360,10 @@ def scan_parquet(
         from environment variables.
     retries
         Number of retries if accessing a cloud instance fails.
+    policy
+        Path to the policy file covering the rows of the scanned files, in order. The
+        policy is registered with the policy monitor when the query is collected with
+        policy checking enabled, see :meth:`LazyFrame.set_policy_checking`.
 
     See Also
     --------
@@ -405,6 +410,7 @@ def scan_parquet(
         hive_partitioning=hive_partitioning,
         hive_schema=hive_schema,
         retries=retries,
+        policy=normalize_filepath(policy) if policy is not None else None,
     )
 
 
@@ -423,6 +429,7 @@ def _scan_parquet_impl(
     hive_partitioning: bool = True,
     hive_schema: SchemaDict | None = None,
     retries: int = 0,
+    policy: str | None = None,
 ) -> LazyFrame:
     if isinstance(source, list):
         sources = source
@@ -438,6 +445,9 @@ def _scan_parquet_impl(
         and not is_local_file(source)  # type: ignore[arg-type]
         and not is_supported_cloud(source)  # type: ignore[arg-type]
     ):
+        if policy is not None:
+            msg = "a policy cannot be attached to a scan that is read through fsspec"
+            raise ValueError(msg)
         scan = _scan_parquet_fsspec(source, storage_options)  # type: ignore[arg-type]
         if n_rows:
             scan = scan.head(n_rows)
@@ -465,5 +475,6 @@ def _scan_parquet_impl(
         hive_partitioning=hive_partitioning,
         hive_schema=hive_schema,
         retries=retries,
+        policy=policy,
     )
     return wrap_ldf(pylf)
@@ -470,6 +470,24 @@ class LazyFrame:
         """
         return self._ldf.width()
 
+    @property
+    def ctx_id(self) -> str:
+        """
+        Get the id of the policy monitor context the LazyFrame is checked in.
+
+        See Also
+        --------
+        set_ctx_id
+        set_policy_checking
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"foo": [1, 2, 3]})
+        >>> lf.ctx_id
+        '00000000-0000-0000-0000-000000000000'
+        """
+        return self._ldf.ctx_id()
+
     def __bool__(self) -> NoReturn:
         msg = (
             "the truth value of a LazyFrame is ambiguous"
@@ -2395,6 +2413,54 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self._from_pyldf(self._ldf.cache())
 
+    def set_policy_checking(self, enabled: bool = True) -> Self:  # noqa: FBT001
+        """
+        Check the query against the policies of its sources when it is collected.
+
+        Every operation of the query is reported to the policy monitor, which raises
+        a :class:`PolicyViolationError` if the query is not allowed by the policies
+        of the scanned data.
+
+        Parameters
+        ----------
+        enabled
+            Whether to check the policies.
+
+        See Also
+        --------
+        set_ctx_id
+
+        Examples
+        --------
+        >>> lf = pl.scan_parquet(
+        ...     "path/to/file.parquet", policy="path/to/file.policy"
+        ... )  # doctest: +SKIP
+        >>> lf.select(pl.col("foo").sum()).set_policy_checking().collect()  # doctest: +SKIP
+        """
+        return self._from_pyldf(self._ldf.set_policy_checking(enabled))
+
+    def set_ctx_id(self, ctx_id: str) -> Self:
+        """
+        Set the id of the policy monitor context the LazyFrame is checked in.
+
+        Parameters
+        ----------
+        ctx_id
+            The id of an open monitor context, as a UUID string.
+
+        See Also
+        --------
+        ctx_id
+        set_policy_checking
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"foo": [1, 2, 3]})
+        >>> lf.set_ctx_id("67e55044-10b1-426f-9247-bb680e5fe0c8").ctx_id
+        '67e55044-10b1-426f-9247-bb680e5fe0c8'
+        """
+        return self._from_pyldf(self._ldf.set_ctx_id(ctx_id))
+
     def cast(
         self,
         dtypes: (
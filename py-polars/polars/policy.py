@@ -0,0 +1,50 @@
+from __future__ import annotations
+
+import contextlib
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+
+def init_monitor() -> None:
+    """
+    Initialize the policy monitor.
+
+    The monitor checks the queries that are collected with policy checking enabled.
+    It has to be initialized once per process, calling this function again is a
+    no-op.
+
+    See Also
+    --------
+    open_new : Open a new context in the monitor.
+    LazyFrame.set_policy_checking : Check a query against the policies of its data.
+
+    Examples
+    --------
+    >>> pl.init_monitor()  # doctest: +SKIP
+    """
+    plr.init_monitor()
+
+
+def open_new() -> str:
+    """
+    Open a new context in the policy monitor and return its id.
+
+    A query is checked in the context that is set with :meth:`LazyFrame.set_ctx_id`.
+    The monitor must be initialized with :func:`init_monitor` first.
+
+    See Also
+    --------
+    init_monitor : Initialize the policy monitor.
+    LazyFrame.set_ctx_id : Set the context a query is checked in.
+
+    Examples
+    --------
+    >>> pl.init_monitor()  # doctest: +SKIP
+    >>> ctx_id = pl.open_new()  # doctest: +SKIP
+    >>> lf = pl.scan_parquet(
+    ...     "path/to/file.parquet", policy="path/to/file.policy"
+    ... )  # doctest: +SKIP
+    >>> lf.set_ctx_id(ctx_id).set_policy_checking().collect()  # doctest: +SKIP
+    """
+    return plr.open_new()
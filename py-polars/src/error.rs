@@ -55,6 +55,7 @@ impl std::convert::From<PyPolarsErr> for PyErr {
                 },
                 PolarsError::NoData(err) => NoDataError::new_err(err.to_string()),
                 PolarsError::OutOfBounds(err) => OutOfBoundsError::new_err(err.to_string()),
+                PolarsError::PolicyViolation(err) => policy_violation_error(&err),
                 PolarsError::SchemaFieldNotFound(name) => {
                     SchemaFieldNotFoundError::new_err(name.to_string())
                 },
@@ -76,6 +77,21 @@ impl std::convert::From<PyPolarsErr> for PyErr {
     }
 }
 
+/// A `PolicyViolationError` that carries the `clause`, `operator` and `column` of the violation
+/// as attributes, next to its message.
+fn policy_violation_error(err: &polars_error::PolicyViolationError) -> PyErr {
+    let py_err = PolicyViolationError::new_err(err.to_string());
+    Python::with_gil(|py| {
+        let value = py_err.value_bound(py);
+        value
+            .setattr("clause", &err.clause)
+            .and_then(|_| value.setattr("operator", err.operator.as_deref()))
+            .and_then(|_| value.setattr("column", err.column.as_deref()))
+            .err()
+    })
+    .unwrap_or(py_err)
+}
+
 impl Debug for PyPolarsErr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use PyPolarsErr::*;
@@ -93,6 +109,7 @@ create_exception!(polars.exceptions, DuplicateError, PolarsBaseError);
 create_exception!(polars.exceptions, InvalidOperationError, PolarsBaseError);
 create_exception!(polars.exceptions, NoDataError, PolarsBaseError);
 create_exception!(polars.exceptions, OutOfBoundsError, PolarsBaseError);
+create_exception!(polars.exceptions, PolicyViolationError, PolarsBaseError);
 create_exception!(polars.exceptions, SchemaError, PolarsBaseError);
 create_exception!(polars.exceptions, SchemaFieldNotFoundError, PolarsBaseError);
 create_exception!(polars.exceptions, ShapeError, PolarsBaseError);
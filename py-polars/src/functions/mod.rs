@@ -5,6 +5,7 @@ mod io;
 mod lazy;
 mod meta;
 mod misc;
+mod policy;
 mod random;
 mod range;
 mod string_cache;
@@ -17,6 +18,7 @@ pub use io::*;
 pub use lazy::*;
 pub use meta::*;
 pub use misc::*;
+pub use policy::*;
 pub use random::*;
 pub use range::*;
 pub use string_cache::*;
@@ -0,0 +1,20 @@
+use picachv::PicachvError;
+use polars::prelude::*;
+use pyo3::prelude::*;
+
+use crate::error::PyPolarsErr;
+
+#[pyfunction]
+pub fn init_monitor() -> PyResult<()> {
+    match picachv::native::init_monitor() {
+        Ok(()) | Err(PicachvError::Already(_)) => Ok(()),
+        Err(e) => Err(PyPolarsErr::from(PolarsError::from(e)).into()),
+    }
+}
+
+#[pyfunction]
+pub fn open_new() -> PyResult<String> {
+    let ctx_id =
+        picachv::native::open_new().map_err(|e| PyPolarsErr::from(PolarsError::from(e)))?;
+    Ok(ctx_id.to_string())
+}
@@ -13,6 +13,7 @@ use polars_core::prelude::*;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict, PyList};
+use uuid::Uuid;
 
 use crate::arrow_interop::to_rust::pyarrow_schema_to_rust;
 use crate::error::PyPolarsErr;
@@ -245,7 +246,7 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (path, paths, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, retries)
+        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, retries, policy)
     )]
     fn new_from_parquet(
         path: Option<PathBuf>,
@@ -261,6 +262,7 @@ impl PyLazyFrame {
         hive_partitioning: bool,
         hive_schema: Option<Wrap<Schema>>,
         retries: usize,
+        policy: Option<PathBuf>,
     ) -> PyResult<Self> {
         let parallel = parallel.0;
         let hive_schema = hive_schema.map(|s| Arc::new(s.0));
@@ -302,6 +304,7 @@ impl PyLazyFrame {
             cloud_options,
             use_statistics,
             hive_options,
+            with_policy: policy.map(ScanPolicy::File),
         };
 
         let lf = if path.is_some() {
@@ -363,6 +366,7 @@ impl PyLazyFrame {
             memory_map,
             #[cfg(feature = "cloud")]
             cloud_options,
+            with_policy: None,
         };
 
         let lf = if let Some(path) = &path {
@@ -550,6 +554,22 @@ impl PyLazyFrame {
         ldf.cache().into()
     }
 
+    fn set_policy_checking(&self, enabled: bool) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.set_policy_checking(enabled).into()
+    }
+
+    fn set_ctx_id(&self, ctx_id: &str) -> PyResult<Self> {
+        let ctx_id = Uuid::parse_str(ctx_id)
+            .map_err(|e| PyValueError::new_err(format!("invalid context id '{ctx_id}': {e}")))?;
+        let ldf = self.ldf.clone();
+        Ok(ldf.set_ctx_id(ctx_id).into())
+    }
+
+    fn ctx_id(&self) -> String {
+        self.ldf.get_ctx_id().to_string()
+    }
+
     fn profile(&self, py: Python) -> PyResult<(PyDataFrame, PyDataFrame)> {
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -58,8 +58,8 @@ use crate::dataframe::PyDataFrame;
 use crate::error::{
     CategoricalRemappingWarning, ColumnNotFoundError, ComputeError, DuplicateError,
     InvalidOperationError, MapWithoutReturnDtypeWarning, NoDataError, OutOfBoundsError,
-    PolarsBaseError, PolarsBaseWarning, PyPolarsErr, SchemaError, SchemaFieldNotFoundError,
-    StructFieldNotFoundError,
+    PolarsBaseError, PolarsBaseWarning, PolicyViolationError, PyPolarsErr, SchemaError,
+    SchemaFieldNotFoundError, StructFieldNotFoundError,
 };
 use crate::expr::PyExpr;
 use crate::functions::PyStringCacheHolder;
@@ -240,6 +240,10 @@ fn polars(py: Python, m: &PyModule) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::using_string_cache))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::init_monitor))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::open_new))
+        .unwrap();
 
     // Numeric formatting
     m.add_wrapped(wrap_pyfunction!(functions::get_thousands_separator))
@@ -293,6 +297,11 @@ fn polars(py: Python, m: &PyModule) -> PyResult<()> {
         .unwrap();
     m.add("PolarsPanicError", py.get_type::<PanicException>())
         .unwrap();
+    m.add(
+        "PolicyViolationError",
+        py.get_type::<PolicyViolationError>(),
+    )
+    .unwrap();
     m.add("SchemaError", py.get_type::<SchemaError>()).unwrap();
     m.add(
         "SchemaFieldNotFoundError",
@@ -432,3 +432,32 @@ def test_scan_deadlock_rayon_spawn_from_async_15172(
     t.join(5)
 
     assert results[0].equals(df)
+
+
+def test_scan_parquet_policy(foods_parquet_path: Path) -> None:
+    policy = foods_parquet_path.with_suffix(".policy")
+    lf = pl.scan_parquet(foods_parquet_path, policy=policy)
+    # The policy is only read when policy checking is enabled.
+    assert_frame_equal(lf.collect(), pl.read_parquet(foods_parquet_path))
+
+    with pytest.raises(ValueError, match="fsspec"):
+        pl.scan_parquet("ftp://host/file.parquet", policy=policy)
+
+
+def test_scan_parquet_policy_checking(tmp_path: Path) -> None:
+    pl.init_monitor()
+    # Initializing the monitor again is a no-op.
+    pl.init_monitor()
+    ctx_id = pl.open_new()
+
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    path = tmp_path / "data.parquet"
+    df.write_parquet(path)
+
+    lf = pl.scan_parquet(path).filter(pl.col("a") > 1).set_ctx_id(ctx_id)
+    assert lf.ctx_id == ctx_id
+    assert_frame_equal(lf.collect(), df.filter(pl.col("a") > 1))
+
+    # The data of a scan without a policy cannot be tracked by the monitor.
+    with pytest.raises(pl.InvalidOperationError, match="scan without a policy"):
+        lf.set_policy_checking(True).collect()
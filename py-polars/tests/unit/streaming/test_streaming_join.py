@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 import numpy as np
 import pandas as pd
@@ -9,6 +9,9 @@ import pytest
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -248,3 +251,37 @@ def test_streaming_join_and_union() -> None:
     out = q.collect(streaming=True)
     assert_frame_equal(out, q.collect(streaming=False))
     assert out.to_series().to_list() == [1, 2, 1, 2, 4, 8, 1, 2]
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("how", ["inner", "left", "outer", "outer_coalesce"])
+def test_streaming_join_ooc(
+    how: Literal["inner", "left", "outer", "outer_coalesce"],
+    tmp_path: Path,
+    monkeypatch: Any,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    np.random.seed(1)
+    n = 10_000
+    dfa = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 500, n),
+            "idx_a": np.arange(0, n),
+        }
+    )
+    dfb = pl.DataFrame(
+        {
+            "a": pl.Series(np.random.randint(0, 600, n // 2)).extend_constant(None, 3),
+            "idx_b": np.arange(0, n // 2 + 3),
+        }
+    )
+
+    q = (
+        dfa.lazy()
+        .join(dfb.lazy(), on="a", how=how)
+        .sort(["idx_a", "idx_b"], nulls_last=True)
+    )
+    assert_frame_equal(q.collect(streaming=True), q.collect(streaming=False))
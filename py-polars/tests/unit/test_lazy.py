@@ -1429,3 +1429,17 @@ def test_lazy_comparison_operators(
         match=f'"{comparators[0]!r}" comparison not supported for LazyFrame objects',
     ):
         comparators[1](pl.LazyFrame(), pl.LazyFrame())
+
+
+def test_policy_checking_settings() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    assert lf.ctx_id == "00000000-0000-0000-0000-000000000000"
+
+    ctx_id = "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    lf = lf.set_ctx_id(ctx_id)
+    assert lf.ctx_id == ctx_id
+
+    with pytest.raises(ValueError, match="invalid context id"):
+        lf.set_ctx_id("not-a-uuid")
+
+    assert_frame_equal(lf.set_policy_checking(False).collect(), lf.collect())